@@ -0,0 +1,332 @@
+use crate::DestinyInventoryItemManifest;
+use crate::inventory::OwnedItem;
+use crate::types::definitions::DestinyInventoryItemDefinition;
+use crate::types::destiny::DestinyItemType;
+
+pub const MOBILITY: u32 = 2996146975;
+pub const RESILIENCE: u32 = 392767087;
+pub const RECOVERY: u32 = 1943323491;
+pub const DISCIPLINE: u32 = 1735777505;
+pub const INTELLECT: u32 = 144602215;
+pub const STRENGTH: u32 = 4244567218;
+
+/// The armor stat hashes in the order the game lists them.
+pub const ARMOR_STATS: [u32; 6] = [
+    MOBILITY, RESILIENCE, RECOVERY, DISCIPLINE, INTELLECT, STRENGTH,
+];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ArmorStats {
+    pub mobility: i32,
+    pub resilience: i32,
+    pub recovery: i32,
+    pub discipline: i32,
+    pub intellect: i32,
+    pub strength: i32,
+}
+
+/// An armor piece's stats split by where they come from. `base` covers the item's intrinsic
+/// roll; `masterwork` and `mods` are the investment stats of the plugs in those sockets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArmorStatBreakdown {
+    pub base: ArmorStats,
+    pub masterwork: ArmorStats,
+    pub mods: ArmorStats,
+    pub total: ArmorStats,
+}
+
+impl ArmorStats {
+    /// Looks a stat up by its `DestinyStatDefinition` hash.
+    pub fn get(&self, stat_hash: u32) -> Option<i32> {
+        Some(match stat_hash {
+            MOBILITY => self.mobility,
+            RESILIENCE => self.resilience,
+            RECOVERY => self.recovery,
+            DISCIPLINE => self.discipline,
+            INTELLECT => self.intellect,
+            STRENGTH => self.strength,
+            _ => return None,
+        })
+    }
+
+    /// `(stat_hash, value)` pairs in [`ARMOR_STATS`] order.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, i32)> + use<> {
+        ARMOR_STATS
+            .map(|hash| (hash, self.get(hash).unwrap_or_default()))
+            .into_iter()
+    }
+
+    /// A plug's unconditional investment stats.
+    pub fn from_plug(plug: &DestinyInventoryItemDefinition) -> Self {
+        let mut stats = Self::default();
+        stats.add_plug(plug);
+        stats
+    }
+
+    fn get_mut(&mut self, stat_hash: u32) -> Option<&mut i32> {
+        Some(match stat_hash {
+            MOBILITY => &mut self.mobility,
+            RESILIENCE => &mut self.resilience,
+            RECOVERY => &mut self.recovery,
+            DISCIPLINE => &mut self.discipline,
+            INTELLECT => &mut self.intellect,
+            STRENGTH => &mut self.strength,
+            _ => return None,
+        })
+    }
+
+    pub fn total(&self) -> i32 {
+        self.mobility
+            + self.resilience
+            + self.recovery
+            + self.discipline
+            + self.intellect
+            + self.strength
+    }
+
+    /// Each stat's tier, one per 10 points up to tier 10.
+    pub fn tiers(&self) -> Self {
+        self.iter()
+            .map(|(hash, value)| (hash, (value / 10).clamp(0, 10)))
+            .collect()
+    }
+
+    /// Adds a plug's unconditional investment stats. Stats other than the six armor stats are
+    /// ignored.
+    fn add_plug(&mut self, plug: &DestinyInventoryItemDefinition) {
+        for stat in plug
+            .investment_stats
+            .iter()
+            .filter(|s| !s.is_conditionally_active)
+        {
+            if let Some(value) = self.get_mut(stat.stat_type_hash) {
+                *value += stat.value;
+            }
+        }
+    }
+}
+
+/// Collects `(stat_hash, value)` pairs, ignoring stats other than the six armor stats.
+impl FromIterator<(u32, i32)> for ArmorStats {
+    fn from_iter<I: IntoIterator<Item = (u32, i32)>>(iter: I) -> Self {
+        let mut stats = Self::default();
+        for (stat_hash, value) in iter {
+            if let Some(stat) = stats.get_mut(stat_hash) {
+                *stat = value;
+            }
+        }
+        stats
+    }
+}
+
+impl std::ops::Add for ArmorStats {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            mobility: self.mobility + rhs.mobility,
+            resilience: self.resilience + rhs.resilience,
+            recovery: self.recovery + rhs.recovery,
+            discipline: self.discipline + rhs.discipline,
+            intellect: self.intellect + rhs.intellect,
+            strength: self.strength + rhs.strength,
+        }
+    }
+}
+
+impl std::ops::Sub for ArmorStats {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            mobility: self.mobility - rhs.mobility,
+            resilience: self.resilience - rhs.resilience,
+            recovery: self.recovery - rhs.recovery,
+            discipline: self.discipline - rhs.discipline,
+            intellect: self.intellect - rhs.intellect,
+            strength: self.strength - rhs.strength,
+        }
+    }
+}
+
+impl ArmorStatBreakdown {
+    /// Splits an armor piece's stats component by source. Enabled plugs whose category names a
+    /// masterwork count towards `masterwork`, and `enhancements.*` plugs towards `mods`; every
+    /// other plug is part of the base roll. Returns `None` for non-armor items and when the
+    /// stats component was not requested.
+    pub fn new(item: &OwnedItem, item_definitions: &DestinyInventoryItemManifest) -> Option<Self> {
+        if item.definition?.item_type != DestinyItemType::Armor {
+            return None;
+        }
+
+        let total = item
+            .stats?
+            .stats
+            .values()
+            .map(|s| (s.stat_hash, s.value))
+            .collect::<ArmorStats>();
+
+        let mut masterwork = ArmorStats::default();
+        let mut mods = ArmorStats::default();
+        let plugs = item
+            .sockets
+            .into_iter()
+            .flat_map(|s| &s.sockets)
+            .filter(|s| s.is_enabled)
+            .filter_map(|s| item_definitions.get(&s.plug_hash.to_string()));
+        for plug in plugs {
+            if is_masterwork(plug) {
+                masterwork.add_plug(plug);
+            } else if is_mod(plug) {
+                mods.add_plug(plug);
+            }
+        }
+
+        Some(Self {
+            base: total - masterwork - mods,
+            masterwork,
+            mods,
+            total,
+        })
+    }
+
+    /// The stats the piece would have with `mods` in place of its current ones. Plug hashes
+    /// without a definition are skipped.
+    pub fn with_mods(
+        &self,
+        mods: &[u32],
+        item_definitions: &DestinyInventoryItemManifest,
+    ) -> ArmorStats {
+        let mut projected = self.base + self.masterwork;
+        for plug in mods
+            .iter()
+            .filter_map(|hash| item_definitions.get(&hash.to_string()))
+        {
+            projected.add_plug(plug);
+        }
+        projected
+    }
+}
+
+pub(crate) fn is_masterwork(plug: &DestinyInventoryItemDefinition) -> bool {
+    plug.plug
+        .as_ref()
+        .is_some_and(|p| p.plug_category_identifier.contains("masterwork"))
+}
+
+pub(crate) fn is_mod(plug: &DestinyInventoryItemDefinition) -> bool {
+    plug.plug
+        .as_ref()
+        .is_some_and(|p| p.plug_category_identifier.starts_with("enhancements"))
+}
+
+/// Artifice mods sit in a socket of their own, beside the regular mod sockets.
+pub(crate) fn is_artifice(plug: &DestinyInventoryItemDefinition) -> bool {
+    plug.plug
+        .as_ref()
+        .is_some_and(|p| p.plug_category_identifier == "enhancements.artifice")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArmorStatBreakdown, ArmorStats, is_artifice, is_masterwork, is_mod};
+    use crate::DestinyInventoryItemManifest;
+    use crate::inventory::Inventory;
+    use crate::test_fixtures::{definitions, profile};
+    use crate::types::ids::ItemInstanceId;
+
+    const ARTIFICE_CHEST: u64 = 6917529800000000101;
+    const EXOTIC_BOND: u64 = 6917529800000000102;
+
+    #[test]
+    fn breakdown_and_projection() {
+        let profile = profile("profile_armor.json");
+        let definitions: DestinyInventoryItemManifest =
+            definitions("DestinyInventoryItemDefinition");
+        let inventory = Inventory::new(&profile, &definitions);
+
+        let chest = inventory.get(ItemInstanceId(ARTIFICE_CHEST)).unwrap();
+        let breakdown = ArmorStatBreakdown::new(chest, &definitions).unwrap();
+        assert_eq!(
+            breakdown.base,
+            ArmorStats {
+                mobility: 2,
+                resilience: 16,
+                recovery: 14,
+                discipline: 10,
+                intellect: 2,
+                strength: 18,
+            }
+        );
+        assert_eq!(breakdown.masterwork.total(), 12);
+        // The resilience mod and the artifice mod; the artifice intrinsic is part of the base.
+        assert_eq!(
+            breakdown.mods,
+            ArmorStats {
+                resilience: 10,
+                discipline: 3,
+                ..ArmorStats::default()
+            }
+        );
+        assert_eq!(breakdown.total.total(), 87);
+
+        let projected = breakdown.with_mods(&[4204488676], &definitions);
+        assert_eq!(projected.resilience, 18);
+        assert_eq!(projected.recovery, 26);
+        assert_eq!(projected.discipline, 12);
+        assert_eq!(projected.total(), 84);
+    }
+
+    /// Exotic class items roll no base stats; their perks are neither mods nor masterworks, and
+    /// the disabled mod in the last socket contributes nothing.
+    #[test]
+    fn exotic_class_item() {
+        let profile = profile("profile_armor.json");
+        let definitions: DestinyInventoryItemManifest =
+            definitions("DestinyInventoryItemDefinition");
+        let inventory = Inventory::new(&profile, &definitions);
+
+        let bond = inventory.get(ItemInstanceId(EXOTIC_BOND)).unwrap();
+        let breakdown = ArmorStatBreakdown::new(bond, &definitions).unwrap();
+        assert_eq!(breakdown.base, ArmorStats::default());
+        assert_eq!(breakdown.masterwork.total(), 12);
+        assert_eq!(breakdown.mods, ArmorStats::default());
+        assert_eq!(breakdown.total.total(), 12);
+    }
+
+    #[test]
+    fn not_armor() {
+        let profile = profile("profile.json");
+        let definitions: DestinyInventoryItemManifest =
+            definitions("DestinyInventoryItemDefinition");
+        let inventory = Inventory::new(&profile, &definitions);
+
+        let weapon = inventory.get(ItemInstanceId(6917529800000000001)).unwrap();
+        assert!(ArmorStatBreakdown::new(weapon, &definitions).is_none());
+    }
+
+    #[test]
+    fn classifies_plugs() {
+        let definitions: DestinyInventoryItemManifest =
+            definitions("DestinyInventoryItemDefinition");
+        for (hash, masterwork, r#mod, artifice) in [
+            // Masterworked Armor, v400.plugs.armor.masterworks.stat.resistance
+            (2493100093_u32, true, false, false),
+            // Recovery Mod, enhancements.v2_general
+            (4204488676, false, true, false),
+            // Discipline-Forged, enhancements.artifice
+            (2000000012, false, true, true),
+            // Artifice Armor, intrinsics
+            (2000000011, false, false, false),
+            // Spirit of the Sample, an exotic class item perk
+            (2000000013, false, false, false),
+            // Sample Helmet, which is not a plug
+            (3299386902, false, false, false),
+        ] {
+            let plug = &definitions[&hash.to_string()];
+            assert_eq!(is_masterwork(plug), masterwork, "{hash}");
+            assert_eq!(is_mod(plug), r#mod, "{hash}");
+            assert_eq!(is_artifice(plug), artifice, "{hash}");
+        }
+    }
+}
@@ -1,28 +1,51 @@
+#[cfg(feature = "lenient")]
+use std::sync::Mutex;
+
 use reqwest::header::HeaderMap;
 use reqwest::{Client, ClientBuilder, IntoUrl, Response, header};
 use serde::de::DeserializeOwned;
+use url::Url;
 
+#[cfg(feature = "lenient")]
+use crate::lenient::{self, UnknownField};
 use crate::types::{exceptions::PlatformErrorCodes, response::BungieResponse};
 use crate::{Error, Result};
 
+const BUNGIE_URL: &str = "https://www.bungie.net/";
+
 pub struct BungieClientBuilder {
     api_key: String,
+    base_url: Option<Url>,
 }
 
 impl BungieClientBuilder {
     pub fn new(api_key: impl Into<String>) -> Self {
         Self {
             api_key: api_key.into(),
+            base_url: None,
         }
     }
 
+    /// Sends requests to another host, such as a local mock server, instead of bungie.net.
+    pub fn base_url(mut self, base_url: Url) -> Self {
+        self.base_url = Some(base_url);
+        self
+    }
+
     pub fn build(self) -> Result<BungieClient> {
-        BungieClient::new(self.api_key)
+        let mut client = BungieClient::new(self.api_key)?;
+        if let Some(base_url) = self.base_url {
+            client.base_url = base_url;
+        }
+        Ok(client)
     }
 }
 
 pub struct BungieClient {
     pub(crate) client: Client,
+    base_url: Url,
+    #[cfg(feature = "lenient")]
+    unknown_fields: Mutex<Vec<UnknownField>>,
 }
 
 impl BungieClient {
@@ -42,16 +65,45 @@ impl BungieClient {
             .build()
             .unwrap();
 
-        Ok(BungieClient { client })
+        Ok(BungieClient {
+            client,
+            base_url: Url::parse(BUNGIE_URL).unwrap(),
+            #[cfg(feature = "lenient")]
+            unknown_fields: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// Drains the unknown fields seen in responses since the last call.
+    #[cfg(feature = "lenient")]
+    pub fn take_unknown_fields(&self) -> Vec<UnknownField> {
+        std::mem::take(&mut *self.unknown_fields.lock().unwrap())
+    }
+
+    /// Resolves `path` against the base URL, e.g. `Platform/Destiny2/Manifest/` or a manifest
+    /// content path.
+    pub(crate) fn url(&self, path: &str) -> Url {
+        self.base_url.join(path).expect("Invalid path")
     }
 
     pub async fn get<T: DeserializeOwned>(&self, url: impl IntoUrl) -> Result<T> {
         let reqwest = self.client.get(url);
-        let mut res = reqwest.send().await.unwrap();
+        let mut res = reqwest.send().await?;
         res = Self::validate_status(res)?;
         res = Self::validate_content_type(res)?;
-        let text = res.text().await.unwrap();
-        match serde_json::from_str::<T>(&text) {
+        let text = res.text().await?;
+
+        // Parsed into a local list so that concurrent requests do not wait on the lock.
+        #[cfg(feature = "lenient")]
+        let result = {
+            let mut unknown_fields = Vec::new();
+            let result = lenient::from_str::<T>(&text, &mut unknown_fields);
+            self.unknown_fields.lock().unwrap().extend(unknown_fields);
+            result
+        };
+        #[cfg(not(feature = "lenient"))]
+        let result = serde_json::from_str::<T>(&text);
+
+        match result {
             Ok(json) => Ok(json),
             Err(e) => {
                 #[cfg(test)]
@@ -96,13 +148,8 @@ impl BungieClient {
     }
 
     pub async fn handle_bungie_response<T>(de: BungieResponse<T>) -> Result<T> {
-        #[allow(unreachable_patterns)]
         match de.error_code {
-            PlatformErrorCodes::Success => Ok(de.response),
-            PlatformErrorCodes::Unknown(code) => {
-                println!("Error Code: {code}");
-                Err(Error::Bungie(PlatformErrorCodes::Unknown(code)))
-            }
+            PlatformErrorCodes::Success => de.response.ok_or(Error::NoResponse),
             code => Err(Error::Bungie(code)),
         }
     }
@@ -0,0 +1,360 @@
+use std::collections::HashMap;
+
+use crate::error::Result;
+use crate::types::definitions::DestinyInventoryItemDefinition;
+use crate::types::destiny::components::craftables::{
+    DestinyCraftableComponent, DestinyCraftableSocketComponent,
+};
+use crate::types::destiny::components::records::DestinyRecordComponent;
+use crate::types::destiny::responses::DestinyProfileResponse;
+use crate::types::ids::CharacterId;
+use crate::{
+    DestinyInventoryItemManifest, DestinyPlugSetManifest, DestinyRecordDefinition,
+    DestinyRecordManifest,
+};
+
+/// The English `recordTypeName` of the records that track a weapon's pattern, for the name
+/// fallback in [`CraftingView::new`].
+const PATTERN_RECORD_TYPE: &str = "Weapon Pattern";
+
+/// A character's weapon recipes, with the plugs each socket has unlocked and the progress
+/// towards the weapon's pattern, sorted by weapon name.
+#[derive(Debug)]
+pub struct CraftingView<'a> {
+    pub weapons: Vec<CraftableWeapon<'a>>,
+}
+
+#[derive(Debug)]
+pub struct CraftableWeapon<'a> {
+    pub recipe_hash: u32,
+    pub recipe: Option<&'a DestinyInventoryItemDefinition>,
+    /// The weapon the recipe crafts.
+    pub output: Option<&'a DestinyInventoryItemDefinition>,
+    pub visible: bool,
+    /// Why the weapon cannot be crafted yet. Empty once it can.
+    pub failed_requirements: Vec<&'a str>,
+    pub sockets: Vec<CraftableSocket<'a>>,
+    /// `None` when no record tracks the pattern or the records components were not requested.
+    pub pattern: Option<PatternProgress<'a>>,
+}
+
+#[derive(Debug)]
+pub struct CraftableSocket<'a> {
+    pub plug_set_hash: u32,
+    pub plugs: Vec<CraftablePlug<'a>>,
+}
+
+#[derive(Debug)]
+pub struct CraftablePlug<'a> {
+    pub plug_item_hash: u32,
+    pub definition: Option<&'a DestinyInventoryItemDefinition>,
+    /// The weapon level the plug unlocks at, from the plug set.
+    pub required_level: Option<i32>,
+    /// Why the plug cannot be chosen yet. Empty once it can.
+    pub failed_requirements: Vec<&'a str>,
+}
+
+#[derive(Debug)]
+pub struct PatternProgress<'a> {
+    pub record_hash: u32,
+    pub definition: &'a DestinyRecordDefinition,
+    pub progress: i32,
+    pub completion_value: i32,
+    pub complete: bool,
+}
+
+impl<'a> CraftingView<'a> {
+    /// Fails when the craftables component was not requested, and is empty when it has no
+    /// entry for `character_id`.
+    ///
+    /// A weapon's pattern record is the one tracking an objective of the recipe item. Recipes
+    /// without objectives fall back to the English pattern record named after the crafted
+    /// weapon, which only works with English definitions since names are localized. Either way
+    /// the lowest hash wins a tie. Progress is read from the profile records, then the
+    /// character's.
+    pub fn new(
+        profile: &'a DestinyProfileResponse,
+        character_id: CharacterId,
+        item_definitions: &'a DestinyInventoryItemManifest,
+        plug_set_definitions: &'a DestinyPlugSetManifest,
+        record_definitions: &'a DestinyRecordManifest,
+    ) -> Result<Self> {
+        let Some(craftables) = profile.character_craftables()?.get(&character_id) else {
+            return Ok(CraftingView {
+                weapons: Vec::new(),
+            });
+        };
+
+        let mut by_objective = HashMap::new();
+        let mut by_name = HashMap::new();
+        for definition in record_definitions.values() {
+            for &objective_hash in &definition.objective_hashes {
+                insert_lowest(&mut by_objective, objective_hash, definition);
+            }
+            if definition.record_type_name.as_deref() == Some(PATTERN_RECORD_TYPE)
+                && !definition.objective_hashes.is_empty()
+            {
+                insert_lowest(
+                    &mut by_name,
+                    definition.display_properties.name.as_str(),
+                    definition,
+                );
+            }
+        }
+        let profile_records = profile.profile_records().ok().map(|r| &r.records);
+        let character_records = profile
+            .character_records()
+            .ok()
+            .and_then(|r| r.get(&character_id))
+            .map(|r| &r.records);
+        let record = |hash: u32| {
+            profile_records
+                .and_then(|r| r.get(&hash))
+                .or_else(|| character_records.and_then(|r| r.get(&hash)))
+        };
+
+        let mut weapons = craftables
+            .craftables
+            .iter()
+            .map(|(&recipe_hash, craftable)| {
+                let recipe = item_definitions.get(&recipe_hash.to_string());
+                let output = recipe
+                    .and_then(|r| r.crafting.as_ref())
+                    .and_then(|c| item_definitions.get(&c.output_item_hash.to_string()));
+                let objectives = recipe
+                    .and_then(|r| r.objectives.as_ref())
+                    .map_or(&[][..], |o| &o.objective_hashes);
+                let pattern = if objectives.is_empty() {
+                    output.and_then(|o| by_name.get(o.display_properties.name.as_str()))
+                } else {
+                    objectives.iter().find_map(|hash| by_objective.get(hash))
+                }
+                .and_then(|&definition| pattern(definition, record(definition.hash)?));
+
+                CraftableWeapon {
+                    recipe_hash,
+                    recipe,
+                    output,
+                    visible: craftable.visible,
+                    failed_requirements: failed_requirements(recipe, craftable),
+                    sockets: craftable
+                        .sockets
+                        .iter()
+                        .map(|s| socket(s, item_definitions, plug_set_definitions))
+                        .collect(),
+                    pattern,
+                }
+            })
+            .collect::<Vec<_>>();
+        weapons.sort_by(|a, b| {
+            a.name()
+                .cmp(b.name())
+                .then(a.recipe_hash.cmp(&b.recipe_hash))
+        });
+
+        Ok(CraftingView { weapons })
+    }
+
+    /// The weapons every requirement is met for.
+    pub fn craftable(&self) -> impl Iterator<Item = &CraftableWeapon<'a>> {
+        self.weapons.iter().filter(|w| w.is_craftable())
+    }
+}
+
+impl<'a> CraftableWeapon<'a> {
+    pub fn is_craftable(&self) -> bool {
+        self.failed_requirements.is_empty()
+    }
+
+    /// The crafted weapon's name, falling back to the recipe's.
+    pub fn name(&self) -> &'a str {
+        self.output
+            .or(self.recipe)
+            .map_or("", |d| d.display_properties.name.as_str())
+    }
+}
+
+impl<'a> CraftableSocket<'a> {
+    pub fn unlocked(&self) -> impl Iterator<Item = &CraftablePlug<'a>> {
+        self.plugs.iter().filter(|p| p.is_unlocked())
+    }
+}
+
+impl CraftablePlug<'_> {
+    pub fn is_unlocked(&self) -> bool {
+        self.failed_requirements.is_empty()
+    }
+}
+
+/// Keeps the record with the lowest hash under each key, so that ties do not depend on the
+/// order of the manifest.
+fn insert_lowest<'a, K: Eq + std::hash::Hash>(
+    map: &mut HashMap<K, &'a DestinyRecordDefinition>,
+    key: K,
+    definition: &'a DestinyRecordDefinition,
+) {
+    map.entry(key)
+        .and_modify(|kept| {
+            if definition.hash < kept.hash {
+                *kept = definition;
+            }
+        })
+        .or_insert(definition);
+}
+
+/// Decodes the recipe's failed requirement indexes against its crafting block. Indexes without
+/// a string are left out.
+fn failed_requirements<'a>(
+    recipe: Option<&'a DestinyInventoryItemDefinition>,
+    craftable: &DestinyCraftableComponent,
+) -> Vec<&'a str> {
+    let strings = recipe
+        .and_then(|r| r.crafting.as_ref())
+        .map_or(&[][..], |c| &c.failed_requirement_strings);
+    decode(&craftable.failed_requirement_indexes, |index| {
+        strings.get(index).map(String::as_str)
+    })
+}
+
+fn socket<'a>(
+    socket: &DestinyCraftableSocketComponent,
+    item_definitions: &'a DestinyInventoryItemManifest,
+    plug_set_definitions: &'a DestinyPlugSetManifest,
+) -> CraftableSocket<'a> {
+    let plug_set = plug_set_definitions.get(&socket.plug_set_hash.to_string());
+
+    CraftableSocket {
+        plug_set_hash: socket.plug_set_hash,
+        plugs: socket
+            .plugs
+            .iter()
+            .map(|plug| {
+                // Plug sets can list a plug more than once; only some entries carry requirements.
+                let requirements = plug_set
+                    .into_iter()
+                    .flat_map(|s| &s.reusable_plug_items)
+                    .filter(|p| p.plug_item_hash == plug.plug_item_hash)
+                    .find_map(|p| p.crafting_requirements.as_ref());
+
+                CraftablePlug {
+                    plug_item_hash: plug.plug_item_hash,
+                    definition: item_definitions.get(&plug.plug_item_hash.to_string()),
+                    required_level: requirements.and_then(|r| r.required_level),
+                    failed_requirements: decode(&plug.failed_requirement_indexes, |index| {
+                        requirements?
+                            .unlock_requirements
+                            .get(index)
+                            .map(|r| r.failure_description.as_str())
+                    }),
+                }
+            })
+            .collect(),
+    }
+}
+
+fn decode<'a>(indexes: &[i32], lookup: impl Fn(usize) -> Option<&'a str>) -> Vec<&'a str> {
+    indexes
+        .iter()
+        .filter_map(|&index| lookup(usize::try_from(index).ok()?))
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Reads the first of the record's objectives that the component reports progress for.
+fn pattern<'a>(
+    definition: &'a DestinyRecordDefinition,
+    record: &DestinyRecordComponent,
+) -> Option<PatternProgress<'a>> {
+    let objective = definition
+        .objective_hashes
+        .iter()
+        .find_map(|&hash| record.objectives.iter().find(|o| o.objective_hash == hash))?;
+
+    Some(PatternProgress {
+        record_hash: definition.hash,
+        definition,
+        progress: objective.progress,
+        completion_value: objective.completion_value,
+        complete: objective.complete,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CraftingView;
+    use crate::test_fixtures::{definitions, profile};
+    use crate::types::ids::CharacterId;
+    use crate::{DestinyInventoryItemManifest, DestinyPlugSetManifest, DestinyRecordManifest};
+
+    #[test]
+    fn crafting_view() {
+        let profile = profile("profile.json");
+        let items: DestinyInventoryItemManifest = definitions("DestinyInventoryItemDefinition");
+        let plug_sets: DestinyPlugSetManifest = definitions("DestinyPlugSetDefinition");
+        let mut records: DestinyRecordManifest = definitions("DestinyRecordDefinition");
+        // A pattern record with the weapon's name but another objective, a triumph with the
+        // name, and a later pattern record with the name and objective.
+        let pattern = records["1458880365"].clone();
+        for (hash, record_type_name, objective_hash) in [
+            (1000000000, pattern.record_type_name.clone(), 1),
+            (1000000001, None, 2),
+            (2000000001, pattern.record_type_name.clone(), 3073265077),
+        ] {
+            let mut copy = pattern.clone();
+            copy.hash = hash;
+            copy.record_type_name = record_type_name;
+            copy.objective_hashes = vec![objective_hash];
+            records.insert(hash.to_string(), copy);
+        }
+
+        let view = CraftingView::new(
+            &profile,
+            CharacterId(2305843009300000001),
+            &items,
+            &plug_sets,
+            &records,
+        )
+        .unwrap();
+        assert_eq!(view.weapons.len(), 1);
+        assert_eq!(view.craftable().count(), 0);
+
+        let weapon = &view.weapons[0];
+        assert_eq!(weapon.name(), "Sample Hand Cannon");
+        assert_eq!(weapon.output.unwrap().hash, 2938480696);
+        assert_eq!(weapon.failed_requirements, ["Requires the weapon pattern"]);
+
+        let unlocked = weapon
+            .sockets
+            .iter()
+            .map(|s| s.unlocked().map(|p| p.plug_item_hash).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(unlocked, [vec![2420895100, 3400784728], vec![3038247973]]);
+        let kill_clip = &weapon.sockets[1].plugs[1];
+        assert_eq!(kill_clip.required_level, Some(4));
+        assert_eq!(kill_clip.failed_requirements, ["Requires weapon level 4"]);
+
+        let pattern = weapon.pattern.as_ref().unwrap();
+        assert_eq!(pattern.record_hash, 1458880365);
+        assert_eq!((pattern.progress, pattern.completion_value), (3, 5));
+        assert!(!pattern.complete);
+
+        let other =
+            CraftingView::new(&profile, CharacterId(1), &items, &plug_sets, &records).unwrap();
+        assert!(other.weapons.is_empty());
+
+        // Without recipe objectives, the pattern is found by the weapon's name.
+        let mut items = items;
+        items.get_mut("3746717895").unwrap().objectives = None;
+        records.remove("1000000000");
+        let view = CraftingView::new(
+            &profile,
+            CharacterId(2305843009300000001),
+            &items,
+            &plug_sets,
+            &records,
+        )
+        .unwrap();
+        let pattern = view.weapons[0].pattern.as_ref().unwrap();
+        assert_eq!(pattern.record_hash, 1458880365);
+    }
+}
@@ -0,0 +1,251 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::armor_stats::ARMOR_STATS;
+use crate::inventory::{Inventory, OwnedItem};
+use crate::types::TierType;
+use crate::types::destiny::{DestinyItemType, ItemState};
+
+/// Copies of one weapon or armor piece, best first.
+#[derive(Debug)]
+pub struct DuplicateGroup<'i, 'a> {
+    pub item_hash: u32,
+    pub copies: Vec<RankedCopy<'i, 'a>>,
+}
+
+#[derive(Debug)]
+pub struct RankedCopy<'i, 'a> {
+    pub item: &'i OwnedItem<'a>,
+    /// How many of the preferred perks are plugged in.
+    pub perk_score: usize,
+    /// The sum of an armor piece's armor stats. Always zero for weapons.
+    pub stat_total: i32,
+    pub suggestion: Suggestion,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Suggestion {
+    /// The highest ranked copy.
+    Best,
+    Locked,
+    Masterwork,
+    Equipped,
+    Dismantle,
+}
+
+/// Groups instanced weapons and armor that share an item hash. Copies rank by preferred perks,
+/// then armor stat total, then power, and every copy below the best is suggested for dismantling
+/// unless it is locked, masterworked or equipped.
+pub fn find_duplicates<'i, 'a>(
+    inventory: &'i Inventory<'a>,
+    preferred_perks: &HashSet<u32>,
+) -> Vec<DuplicateGroup<'i, 'a>> {
+    let mut groups: BTreeMap<u32, Vec<&OwnedItem>> = BTreeMap::new();
+    for item in &inventory.items {
+        let gear = item.definition.is_some_and(|d| {
+            matches!(
+                d.item_type,
+                DestinyItemType::Weapon | DestinyItemType::Armor
+            )
+        });
+        if gear && item.item.item_instance_id.is_some() {
+            groups.entry(item.item.item_hash).or_default().push(item);
+        }
+    }
+
+    groups
+        .into_iter()
+        .filter(|(_, items)| items.len() > 1)
+        .map(|(item_hash, items)| {
+            let mut copies = items
+                .into_iter()
+                .map(|item| RankedCopy {
+                    item,
+                    perk_score: perk_score(item, preferred_perks),
+                    stat_total: stat_total(item),
+                    suggestion: Suggestion::Dismantle,
+                })
+                .collect::<Vec<_>>();
+
+            copies.sort_by_key(|c| std::cmp::Reverse((c.perk_score, c.stat_total, power(c.item))));
+            for (rank, copy) in copies.iter_mut().enumerate() {
+                let state = copy.item.item.state;
+                copy.suggestion = if rank == 0 {
+                    Suggestion::Best
+                } else if state.contains(ItemState::Locked) {
+                    Suggestion::Locked
+                } else if state.contains(ItemState::Masterwork) {
+                    Suggestion::Masterwork
+                } else if copy.item.equipped {
+                    Suggestion::Equipped
+                } else {
+                    Suggestion::Dismantle
+                };
+            }
+
+            DuplicateGroup { item_hash, copies }
+        })
+        .collect()
+}
+
+/// Unlocked, unequipped weapons and armor below legendary, which are safe to dismantle
+/// whether or not they are duplicated.
+pub fn find_junk<'i, 'a>(inventory: &'i Inventory<'a>) -> Vec<&'i OwnedItem<'a>> {
+    inventory
+        .items
+        .iter()
+        .filter(|item| {
+            item.definition.is_some_and(|d| {
+                matches!(
+                    d.item_type,
+                    DestinyItemType::Weapon | DestinyItemType::Armor
+                ) && matches!(
+                    d.inventory.tier_type,
+                    TierType::Basic | TierType::Common | TierType::Rare
+                )
+            }) && !item.equipped
+                && !item.item.state.contains(ItemState::Locked)
+        })
+        .collect()
+}
+
+impl DuplicateGroup<'_, '_> {
+    pub fn to_dismantle(&self) -> impl Iterator<Item = &RankedCopy<'_, '_>> {
+        self.copies
+            .iter()
+            .filter(|c| c.suggestion == Suggestion::Dismantle)
+    }
+}
+
+fn perk_score(item: &OwnedItem, preferred_perks: &HashSet<u32>) -> usize {
+    item.sockets.map_or(0, |sockets| {
+        sockets
+            .sockets
+            .iter()
+            .filter(|s| s.is_enabled && preferred_perks.contains(&s.plug_hash))
+            .count()
+    })
+}
+
+/// Weapon stats such as rounds per minute trade off against each other, so only armor has a
+/// total worth ranking by.
+fn stat_total(item: &OwnedItem) -> i32 {
+    let armor = item
+        .definition
+        .is_some_and(|d| matches!(d.item_type, DestinyItemType::Armor));
+    if !armor {
+        return 0;
+    }
+
+    item.stats.map_or(0, |stats| {
+        stats
+            .stats
+            .values()
+            .filter(|s| ARMOR_STATS.contains(&s.stat_hash))
+            .map(|s| s.value)
+            .sum()
+    })
+}
+
+fn power(item: &OwnedItem) -> i32 {
+    item.instance.map_or(0, |i| i.primary_stat.value)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use serde_json::Value;
+
+    use super::{Suggestion, find_duplicates, find_junk};
+    use crate::DestinyInventoryItemManifest;
+    use crate::armor_stats::RESILIENCE;
+    use crate::inventory::Inventory;
+    use crate::test_fixtures::{self, definitions, fixture, parse_profile, set_plugs, set_stats};
+    use crate::types::ids::ItemInstanceId;
+
+    const HAND_CANNON: &str = "6917529800000000003";
+    const HELMET: &str = "6917529800000000002";
+    const IMPACT: u32 = 4043523819;
+
+    /// Adds a copy of the `source` instance under `id` with its own state and power.
+    fn add_copy(profile: &mut Value, source: &str, id: &str, state: u32, power: i32) {
+        test_fixtures::add_copy(profile, source, id)["state"] = state.into();
+        test_fixtures::component(profile, "instances", id)["primaryStat"]["value"] = power.into();
+    }
+
+    /// A locked copy with more power beats the vaulted hand cannon, which is kept for its
+    /// masterwork. A bare copy without the perk ranks last even with the most power.
+    #[test]
+    fn ranks_and_suggests() {
+        let mut profile: Value = fixture("profile.json");
+        add_copy(&mut profile, HAND_CANNON, "6917529800000000004", 1, 2000);
+        set_plugs(&mut profile, "6917529800000000004", &[3038247973]);
+        add_copy(&mut profile, HAND_CANNON, "6917529800000000005", 0, 2010);
+        set_plugs(&mut profile, "6917529800000000005", &[]);
+        let profile = parse_profile(profile);
+        let definitions: DestinyInventoryItemManifest =
+            definitions("DestinyInventoryItemDefinition");
+        let inventory = Inventory::new(&profile, &definitions);
+
+        let groups = find_duplicates(&inventory, &HashSet::from([3038247973]));
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].item_hash, 2938480696);
+
+        let ranked = groups[0]
+            .copies
+            .iter()
+            .map(|c| {
+                (
+                    c.item.item.item_instance_id.unwrap().0,
+                    c.stat_total,
+                    c.suggestion,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ranked,
+            [
+                (6917529800000000004, 0, Suggestion::Best),
+                (6917529800000000003, 0, Suggestion::Masterwork),
+                (6917529800000000005, 0, Suggestion::Dismantle),
+            ]
+        );
+
+        let dismantle = groups[0]
+            .to_dismantle()
+            .map(|c| c.item.item.item_instance_id.unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(dismantle, [ItemInstanceId(6917529800000000005)]);
+        assert!(find_junk(&inventory).is_empty());
+    }
+
+    /// Armor ranks by its six armor stats before power. Other stats on the piece do not count.
+    #[test]
+    fn ranks_armor_by_armor_stats() {
+        let mut profile: Value = fixture("profile.json");
+        add_copy(&mut profile, HELMET, "6917529800000000006", 0, 1990);
+        set_stats(&mut profile, "6917529800000000006", [(RESILIENCE, 32)]);
+        add_copy(&mut profile, HELMET, "6917529800000000007", 0, 2010);
+        set_stats(&mut profile, "6917529800000000007", [(IMPACT, 500)]);
+        let profile = parse_profile(profile);
+        let definitions: DestinyInventoryItemManifest =
+            definitions("DestinyInventoryItemDefinition");
+        let inventory = Inventory::new(&profile, &definitions);
+
+        let groups = find_duplicates(&inventory, &HashSet::new());
+        assert_eq!(groups.len(), 1);
+        let ranked = groups[0]
+            .copies
+            .iter()
+            .map(|c| (c.item.item.item_instance_id.unwrap().0, c.stat_total))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ranked,
+            [
+                (6917529800000000006, 74),
+                (6917529800000000007, 62),
+                (6917529800000000002, 62),
+            ]
+        );
+    }
+}
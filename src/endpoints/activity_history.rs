@@ -0,0 +1,255 @@
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use futures::stream;
+
+use crate::types::BungieMembershipType;
+use crate::types::destiny::DestinyComponentType;
+use crate::types::destiny::historical_stats::DestinyHistoricalStatsPeriodGroup;
+use crate::types::destiny::historical_stats::definitions::DestinyActivityModeType;
+use crate::types::ids::{ActivityInstanceId, CharacterId, MembershipId};
+use crate::{BungieClient, Result};
+
+const PAGE_SIZE: i32 = 250;
+
+/// Where an activity history stream stops. Activities at or before the cutoff are not yielded.
+#[derive(Debug, Clone, Copy)]
+pub enum ActivityHistoryCutoff {
+    Date(DateTime<Utc>),
+    /// Stops at the given activity instance. If no character played it, the whole history is
+    /// walked.
+    Activity(ActivityInstanceId),
+}
+
+struct CharacterCursor {
+    character_id: CharacterId,
+    page: u32,
+    buffer: VecDeque<DestinyHistoricalStatsPeriodGroup>,
+    exhausted: bool,
+}
+
+struct ActivityHistoryState<'a> {
+    client: &'a BungieClient,
+    membership_type: BungieMembershipType,
+    membership_id: MembershipId,
+    mode: Option<DestinyActivityModeType>,
+    cutoff: Option<ActivityHistoryCutoff>,
+    cursors: Option<Vec<CharacterCursor>>,
+    character_ids: Vec<CharacterId>,
+    done: bool,
+}
+
+impl BungieClient {
+    /// Walks every page of activity history, newest first, merging characters in time order.
+    /// An empty `character_ids` walks every character on the profile.
+    pub fn activity_history_stream(
+        &self,
+        membership_type: BungieMembershipType,
+        membership_id: MembershipId,
+        character_ids: &[CharacterId],
+        mode: Option<DestinyActivityModeType>,
+        cutoff: Option<ActivityHistoryCutoff>,
+    ) -> impl Stream<Item = Result<DestinyHistoricalStatsPeriodGroup>> + '_ {
+        let state = ActivityHistoryState {
+            client: self,
+            membership_type,
+            membership_id,
+            mode,
+            cutoff,
+            cursors: None,
+            character_ids: character_ids.to_vec(),
+            done: false,
+        };
+
+        stream::unfold(state, |mut state| async move {
+            if state.done {
+                return None;
+            }
+
+            match state.next().await {
+                Ok(Some(activity)) => Some((Ok(activity), state)),
+                Ok(None) => None,
+                Err(e) => {
+                    state.done = true;
+                    Some((Err(e), state))
+                }
+            }
+        })
+    }
+}
+
+impl ActivityHistoryState<'_> {
+    async fn next(&mut self) -> Result<Option<DestinyHistoricalStatsPeriodGroup>> {
+        if self.cursors.is_none() {
+            self.cursors = Some(self.init_cursors().await?);
+        }
+
+        let cursors = self.cursors.as_mut().unwrap();
+
+        for cursor in cursors.iter_mut() {
+            if cursor.buffer.is_empty() && !cursor.exhausted {
+                let results = self
+                    .client
+                    .activity_history(
+                        self.membership_type,
+                        self.membership_id,
+                        cursor.character_id,
+                        Some(PAGE_SIZE),
+                        self.mode,
+                        cursor.page,
+                    )
+                    .await?;
+
+                cursor.page += 1;
+                cursor.exhausted = results.activities.len() < PAGE_SIZE as usize;
+                cursor.buffer.extend(results.activities);
+            }
+        }
+
+        let newest = cursors
+            .iter_mut()
+            .filter(|c| !c.buffer.is_empty())
+            .max_by_key(|c| c.buffer[0].period);
+
+        let Some(cursor) = newest else {
+            return Ok(None);
+        };
+
+        let activity = cursor.buffer.pop_front().unwrap();
+
+        match self.cutoff {
+            Some(ActivityHistoryCutoff::Date(date)) if activity.period <= date => {
+                self.done = true;
+                Ok(None)
+            }
+            Some(ActivityHistoryCutoff::Activity(instance_id))
+                if activity.activity_details.instance_id == instance_id =>
+            {
+                self.done = true;
+                Ok(None)
+            }
+            _ => Ok(Some(activity)),
+        }
+    }
+
+    async fn init_cursors(&mut self) -> Result<Vec<CharacterCursor>> {
+        if self.character_ids.is_empty() {
+            let profile = self
+                .client
+                .profile(
+                    self.membership_type,
+                    self.membership_id,
+                    &[DestinyComponentType::Profiles],
+                )
+                .await?;
+
+            self.character_ids = profile.profile()?.character_ids.clone();
+        }
+
+        Ok(self
+            .character_ids
+            .iter()
+            .map(|&character_id| CharacterCursor {
+                character_id,
+                page: 0,
+                buffer: VecDeque::new(),
+                exhausted: false,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+    use futures::StreamExt;
+    use serde_json::{Value, json};
+
+    use super::ActivityHistoryCutoff;
+    use crate::mock_server::{MockBungieServer, MockResponse};
+    use crate::test_fixtures::fixture;
+    use crate::types::BungieMembershipType;
+    use crate::types::ids::{ActivityInstanceId, CharacterId, MembershipId};
+
+    const HUNTER: CharacterId = CharacterId(2305843009300000001);
+    const TITAN: CharacterId = CharacterId(2305843009300000002);
+
+    fn activity(instance_id: u64, period: &str) -> Value {
+        let history: Value = fixture("activity_history.json");
+        let mut activity = history["Response"]["activities"][0].clone();
+        activity["period"] = period.into();
+        activity["activityDetails"]["instanceId"] = instance_id.to_string().into();
+        activity
+    }
+
+    async fn server() -> MockBungieServer {
+        let server = MockBungieServer::start().await;
+        for (character_id, activities) in [
+            (
+                HUNTER,
+                [
+                    activity(4, "2024-06-04T17:00:00Z"),
+                    activity(2, "2024-06-04T15:00:00Z"),
+                ],
+            ),
+            (
+                TITAN,
+                [
+                    activity(3, "2024-06-04T16:00:00Z"),
+                    activity(1, "2024-06-04T14:00:00Z"),
+                ],
+            ),
+        ] {
+            server
+                .mount(
+                    &format!("/Character/{character_id}/Stats/Activities/$"),
+                    MockResponse::ok(json!({ "activities": activities })),
+                )
+                .await;
+        }
+        server
+    }
+
+    async fn instance_ids(
+        server: &MockBungieServer,
+        cutoff: Option<ActivityHistoryCutoff>,
+    ) -> Vec<u64> {
+        server
+            .client()
+            .activity_history_stream(
+                BungieMembershipType::TigerSteam,
+                MembershipId(4611686018400000001),
+                &[HUNTER, TITAN],
+                None,
+                cutoff,
+            )
+            .map(|activity| activity.unwrap().activity_details.instance_id.0)
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn merges_characters_newest_first() {
+        let server = server().await;
+        assert_eq!(instance_ids(&server, None).await, [4, 3, 2, 1]);
+    }
+
+    #[tokio::test]
+    async fn stops_at_cutoff() {
+        let server = server().await;
+        let date = "2024-06-04T15:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            instance_ids(&server, Some(ActivityHistoryCutoff::Date(date))).await,
+            [4, 3]
+        );
+        assert_eq!(
+            instance_ids(
+                &server,
+                Some(ActivityHistoryCutoff::Activity(ActivityInstanceId(3)))
+            )
+            .await,
+            [4]
+        );
+    }
+}
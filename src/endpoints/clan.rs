@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use crate::types::destiny::historical_stats::definitions::DestinyActivityModeType;
+use crate::types::destiny::historical_stats::{DestinyClanAggregateStat, DestinyLeaderboard};
+use crate::types::destiny::milestones::DestinyMilestone;
+use crate::{BungieClient, Result};
+
+use super::join_modes;
+
+impl BungieClient {
+    pub async fn clan_weekly_reward_state(&self, group_id: u64) -> Result<DestinyMilestone> {
+        let mut url = self.url("Platform/Destiny2/Clan/");
+
+        url.path_segments_mut()
+            .expect("Cannot set path segments")
+            .pop_if_empty()
+            .push(&group_id.to_string())
+            .push("WeeklyRewardState")
+            .push("");
+
+        self.get_bungie_response::<DestinyMilestone>(url).await
+    }
+
+    pub async fn clan_aggregate_stats(
+        &self,
+        group_id: u64,
+        modes: &[DestinyActivityModeType],
+    ) -> Result<Vec<DestinyClanAggregateStat>> {
+        let mut url = self.url("Platform/Destiny2/Stats/AggregateClanStats/");
+
+        url.path_segments_mut()
+            .expect("Cannot set path segments")
+            .pop_if_empty()
+            .push(&group_id.to_string())
+            .push("");
+
+        if !modes.is_empty() {
+            url.query_pairs_mut()
+                .append_pair("modes", &join_modes(modes));
+        }
+
+        self.get_bungie_response::<Vec<DestinyClanAggregateStat>>(url)
+            .await
+    }
+
+    /// Leaderboards are keyed by mode name, then by stat id.
+    pub async fn clan_leaderboards(
+        &self,
+        group_id: u64,
+        modes: &[DestinyActivityModeType],
+        max_top: Option<i32>,
+        stat_id: Option<&str>,
+    ) -> Result<HashMap<String, HashMap<String, DestinyLeaderboard>>> {
+        let mut url = self.url("Platform/Destiny2/Stats/Leaderboards/Clans/");
+
+        url.path_segments_mut()
+            .expect("Cannot set path segments")
+            .pop_if_empty()
+            .push(&group_id.to_string())
+            .push("");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+            if !modes.is_empty() {
+                query_pairs.append_pair("modes", &join_modes(modes));
+            }
+            if let Some(max_top) = max_top {
+                query_pairs.append_pair("maxtop", &max_top.to_string());
+            }
+            if let Some(stat_id) = stat_id {
+                query_pairs.append_pair("statid", stat_id);
+            }
+        }
+
+        self.get_bungie_response::<HashMap<String, HashMap<String, DestinyLeaderboard>>>(url)
+            .await
+    }
+}
@@ -0,0 +1,36 @@
+// @generated by `cargo xtask codegen`. Do not edit by hand.
+
+#![allow(clippy::too_many_arguments)]
+
+use crate::{BungieClient, Result};
+
+impl BungieClient {
+    /// Gets any active global alert for display in the forum banners, help pages, etc. Usually used for DOC alerts.
+    pub async fn get_global_alerts(
+        &self,
+        includestreaming: Option<bool>,
+    ) -> Result<Vec<crate::types::GlobalAlert>> {
+        let mut url = self.url("Platform/");
+        url.path_segments_mut()
+            .expect("Cannot set path segments")
+            .pop_if_empty()
+            .push("GlobalAlerts")
+            .push("");
+        {
+            let mut query_pairs = url.query_pairs_mut();
+            if let Some(includestreaming) = includestreaming {
+                query_pairs.append_pair("includestreaming", &query_value(&includestreaming));
+            }
+        }
+        self.get_bungie_response::<Vec<crate::types::GlobalAlert>>(url)
+            .await
+    }
+}
+
+/// Formats a path or query value the way it serializes, without quotes.
+fn query_value<T: serde::Serialize>(value: &T) -> String {
+    match serde_json::to_value(value).unwrap() {
+        serde_json::Value::String(s) => s,
+        value => value.to_string(),
+    }
+}
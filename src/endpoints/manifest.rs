@@ -1,19 +1,26 @@
 use std::collections::HashMap;
 
+use serde::de::DeserializeOwned;
+
 use crate::Result;
 use crate::bungie_client::BungieClient;
 use crate::types::definitions::DestinyInventoryItemDefinition;
 use crate::types::destiny::config::DestinyManifest;
+use crate::types::destiny::definitions::activities::{
+    DestinyActivityDefinition, DestinyActivityModifierDefinition,
+};
+use crate::types::destiny::definitions::milestones::DestinyMilestoneDefinition;
+use crate::types::destiny::definitions::presentation::DestinyPresentationNodeDefinition;
+use crate::types::destiny::definitions::records::DestinyRecordDefinition;
 use crate::types::destiny::definitions::sockets::{
     DestinyPlugSetDefinition, DestinySocketCategoryDefinition, DestinySocketTypeDefinition,
 };
+use crate::types::destiny::definitions::vendors::DestinyVendorDefinition;
 
 impl BungieClient {
     pub async fn destiny_manifest(&self) -> Result<DestinyManifest> {
-        self.get_bungie_response::<DestinyManifest>(
-            "https://www.bungie.net/Platform/Destiny2/Manifest/",
-        )
-        .await
+        self.get_bungie_response::<DestinyManifest>(self.url("Platform/Destiny2/Manifest/"))
+            .await
     }
 
     pub async fn destiny_inventory_item_definition(
@@ -21,16 +28,7 @@ impl BungieClient {
         manifest: &DestinyManifest,
         local: &str,
     ) -> Result<HashMap<String, DestinyInventoryItemDefinition>> {
-        let item_definition_path = manifest
-            .json_world_component_content_paths
-            .get(local)
-            .unwrap()
-            .get("DestinyInventoryItemDefinition")
-            .unwrap();
-
-        let url = format!("https://www.bungie.net{item_definition_path}");
-
-        self.get::<HashMap<String, DestinyInventoryItemDefinition>>(url)
+        self.destiny_definition(manifest, local, "DestinyInventoryItemDefinition")
             .await
     }
 
@@ -39,16 +37,7 @@ impl BungieClient {
         manifest: &DestinyManifest,
         local: &str,
     ) -> Result<HashMap<String, DestinySocketTypeDefinition>> {
-        let item_definition_path = manifest
-            .json_world_component_content_paths
-            .get(local)
-            .unwrap()
-            .get("DestinySocketTypeDefinition")
-            .unwrap();
-
-        let url = format!("https://www.bungie.net{item_definition_path}");
-
-        self.get::<HashMap<String, DestinySocketTypeDefinition>>(url)
+        self.destiny_definition(manifest, local, "DestinySocketTypeDefinition")
             .await
     }
 
@@ -57,16 +46,7 @@ impl BungieClient {
         manifest: &DestinyManifest,
         local: &str,
     ) -> Result<HashMap<String, DestinySocketCategoryDefinition>> {
-        let item_definition_path = manifest
-            .json_world_component_content_paths
-            .get(local)
-            .unwrap()
-            .get("DestinySocketCategoryDefinition")
-            .unwrap();
-
-        let url = format!("https://www.bungie.net{item_definition_path}");
-
-        self.get::<HashMap<String, DestinySocketCategoryDefinition>>(url)
+        self.destiny_definition(manifest, local, "DestinySocketCategoryDefinition")
             .await
     }
 
@@ -75,16 +55,79 @@ impl BungieClient {
         manifest: &DestinyManifest,
         local: &str,
     ) -> Result<HashMap<String, DestinyPlugSetDefinition>> {
-        let item_definition_path = manifest
+        self.destiny_definition(manifest, local, "DestinyPlugSetDefinition")
+            .await
+    }
+
+    pub async fn destiny_activity_definition(
+        &self,
+        manifest: &DestinyManifest,
+        local: &str,
+    ) -> Result<HashMap<String, DestinyActivityDefinition>> {
+        self.destiny_definition(manifest, local, "DestinyActivityDefinition")
+            .await
+    }
+
+    pub async fn destiny_activity_modifier_definition(
+        &self,
+        manifest: &DestinyManifest,
+        local: &str,
+    ) -> Result<HashMap<String, DestinyActivityModifierDefinition>> {
+        self.destiny_definition(manifest, local, "DestinyActivityModifierDefinition")
+            .await
+    }
+
+    pub async fn destiny_vendor_definition(
+        &self,
+        manifest: &DestinyManifest,
+        local: &str,
+    ) -> Result<HashMap<String, DestinyVendorDefinition>> {
+        self.destiny_definition(manifest, local, "DestinyVendorDefinition")
+            .await
+    }
+
+    pub async fn destiny_milestone_definition(
+        &self,
+        manifest: &DestinyManifest,
+        local: &str,
+    ) -> Result<HashMap<String, DestinyMilestoneDefinition>> {
+        self.destiny_definition(manifest, local, "DestinyMilestoneDefinition")
+            .await
+    }
+
+    pub async fn destiny_record_definition(
+        &self,
+        manifest: &DestinyManifest,
+        local: &str,
+    ) -> Result<HashMap<String, DestinyRecordDefinition>> {
+        self.destiny_definition(manifest, local, "DestinyRecordDefinition")
+            .await
+    }
+
+    pub async fn destiny_presentation_node_definition(
+        &self,
+        manifest: &DestinyManifest,
+        local: &str,
+    ) -> Result<HashMap<String, DestinyPresentationNodeDefinition>> {
+        self.destiny_definition(manifest, local, "DestinyPresentationNodeDefinition")
+            .await
+    }
+
+    async fn destiny_definition<T: DeserializeOwned>(
+        &self,
+        manifest: &DestinyManifest,
+        local: &str,
+        definition: &str,
+    ) -> Result<HashMap<String, T>> {
+        let definition_path = manifest
             .json_world_component_content_paths
             .get(local)
             .unwrap()
-            .get("DestinyPlugSetDefinition")
+            .get(definition)
             .unwrap();
 
-        let url = format!("https://www.bungie.net{item_definition_path}");
+        let url = self.url(definition_path);
 
-        self.get::<HashMap<String, DestinyPlugSetDefinition>>(url)
-            .await
+        self.get::<HashMap<String, T>>(url).await
     }
 }
@@ -0,0 +1,30 @@
+use std::collections::HashMap;
+
+use crate::types::destiny::milestones::{DestinyMilestoneContent, DestinyPublicMilestone};
+use crate::{BungieClient, Result};
+
+impl BungieClient {
+    pub async fn public_milestones(&self) -> Result<HashMap<u32, DestinyPublicMilestone>> {
+        self.get_bungie_response::<HashMap<u32, DestinyPublicMilestone>>(
+            self.url("Platform/Destiny2/Milestones/"),
+        )
+        .await
+    }
+
+    pub async fn public_milestone_content(
+        &self,
+        milestone_hash: u32,
+    ) -> Result<DestinyMilestoneContent> {
+        let mut url = self.url("Platform/Destiny2/Milestones/");
+
+        url.path_segments_mut()
+            .expect("Cannot set path segments")
+            .pop_if_empty()
+            .push(&milestone_hash.to_string())
+            .push("Content")
+            .push("");
+
+        self.get_bungie_response::<DestinyMilestoneContent>(url)
+            .await
+    }
+}
@@ -1,6 +1,14 @@
+mod activity_history;
+mod clan;
+mod generated;
 mod manifest;
+mod milestones;
+mod stats;
 
-use url::Url;
+use std::collections::HashSet;
+
+pub use activity_history::ActivityHistoryCutoff;
+use futures::{Stream, StreamExt, stream};
 
 use crate::types::BungieMembershipType;
 use crate::types::destiny::DestinyComponentType;
@@ -9,6 +17,7 @@ use crate::types::destiny::historical_stats::{
     DestinyActivityHistoryResults, DestinyPostGameCarnageReportData,
 };
 use crate::types::destiny::responses::DestinyProfileResponse;
+use crate::types::ids::{ActivityInstanceId, CharacterId, MembershipId};
 use crate::types::user::UserInfoCard;
 use crate::{BungieClient, Result};
 
@@ -18,12 +27,13 @@ impl BungieClient {
         username: &str,
         discriminator: u16,
     ) -> Result<Vec<UserInfoCard>> {
-        let mut url =
-            Url::parse("https://www.bungie.net/Platform/Destiny2/SearchDestinyPlayer/-1/").unwrap();
+        let mut url = self.url("Platform/Destiny2/SearchDestinyPlayer/-1/");
 
         url.path_segments_mut()
             .expect("Cannot set path segments")
-            .push(format!("{username}#{discriminator}").as_str());
+            .pop_if_empty()
+            .push(format!("{username}#{discriminator}").as_str())
+            .push("");
 
         self.get_bungie_response::<Vec<UserInfoCard>>(url).await
     }
@@ -31,21 +41,23 @@ impl BungieClient {
     pub async fn profile(
         &self,
         membership_type: BungieMembershipType,
-        membership_id: u64,
+        membership_id: MembershipId,
         components: &[DestinyComponentType],
     ) -> Result<DestinyProfileResponse> {
-        let mut url = Url::parse("https://www.bungie.net/Platform/Destiny2/").unwrap();
+        let mut url = self.url("Platform/Destiny2/");
 
         url.path_segments_mut()
             .expect("Cannot set path segments")
-            .push(&(membership_type as i16).to_string())
+            .pop_if_empty()
+            .push(&i32::from(membership_type).to_string())
             .push("Profile")
-            .push(&membership_id.to_string());
+            .push(&membership_id.to_string())
+            .push("");
 
         let components = components
             .iter()
             .copied()
-            .map(|c| (c as u16).to_string())
+            .map(|c| i32::from(c).to_string())
             .collect::<Vec<_>>()
             .join(",");
 
@@ -58,23 +70,25 @@ impl BungieClient {
     pub async fn activity_history(
         &self,
         membership_type: BungieMembershipType,
-        membership_id: u64,
-        character_id: u64,
+        membership_id: MembershipId,
+        character_id: CharacterId,
         count: Option<i32>,
         mode: Option<DestinyActivityModeType>,
         page: u32,
     ) -> Result<DestinyActivityHistoryResults> {
-        let mut url = Url::parse("https://www.bungie.net/Platform/Destiny2/").unwrap();
+        let mut url = self.url("Platform/Destiny2/");
 
         url.path_segments_mut()
             .expect("Cannot set path segments")
-            .push(&(membership_type as i16).to_string())
+            .pop_if_empty()
+            .push(&i32::from(membership_type).to_string())
             .push("Account")
             .push(&membership_id.to_string())
             .push("Character")
             .push(&character_id.to_string())
             .push("Stats")
-            .push("Activities");
+            .push("Activities")
+            .push("");
 
         {
             let mut query_pairs = url.query_pairs_mut();
@@ -82,7 +96,7 @@ impl BungieClient {
                 query_pairs.append_pair("count", &count.to_string());
             }
             if let Some(mode) = mode {
-                query_pairs.append_pair("mode", &(mode as i32).to_string());
+                query_pairs.append_pair("mode", &i32::from(mode).to_string());
             }
             query_pairs.append_pair("page", &page.to_string());
         }
@@ -93,14 +107,42 @@ impl BungieClient {
 
     pub async fn post_game_carnage_report(
         &self,
-        activity_id: u64,
+        activity_id: ActivityInstanceId,
     ) -> Result<DestinyPostGameCarnageReportData> {
-        let url = Url::parse(&format!(
-            "https://www.bungie.net/Platform/Destiny2/Stats/PostGameCarnageReport/{activity_id}/"
-        ))
-        .unwrap();
+        let url = self.url(&format!(
+            "Platform/Destiny2/Stats/PostGameCarnageReport/{activity_id}/"
+        ));
 
         self.get_bungie_response::<DestinyPostGameCarnageReportData>(url)
             .await
     }
+
+    /// Fetches reports with at most `concurrency` requests in flight, yielding them as they
+    /// complete. Duplicate instance IDs are requested once, and a failed report does not stop
+    /// the rest of the batch.
+    pub fn post_game_carnage_report_many(
+        &self,
+        activity_ids: impl IntoIterator<Item = ActivityInstanceId>,
+        concurrency: usize,
+    ) -> impl Stream<Item = (ActivityInstanceId, Result<DestinyPostGameCarnageReportData>)> + '_
+    {
+        let mut seen = HashSet::new();
+        let activity_ids = activity_ids
+            .into_iter()
+            .filter(|id| seen.insert(*id))
+            .collect::<Vec<_>>();
+
+        stream::iter(activity_ids)
+            .map(move |id| async move { (id, self.post_game_carnage_report(id).await) })
+            .buffer_unordered(concurrency.max(1))
+    }
+}
+
+fn join_modes(modes: &[DestinyActivityModeType]) -> String {
+    modes
+        .iter()
+        .copied()
+        .map(|m| i32::from(m).to_string())
+        .collect::<Vec<_>>()
+        .join(",")
 }
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use url::Url;
+
+use crate::types::BungieMembershipType;
+use crate::types::destiny::historical_stats::definitions::{
+    DestinyActivityModeType, DestinyHistoricalStatsDefinition, DestinyStatsGroupType, PeriodType,
+};
+use crate::types::destiny::historical_stats::{
+    DestinyAggregateActivityResults, DestinyHistoricalStatsAccountResult,
+    DestinyHistoricalStatsByPeriod, DestinyHistoricalWeaponStatsData,
+};
+use crate::types::ids::{CharacterId, MembershipId};
+use crate::{BungieClient, Result};
+
+use super::join_modes;
+
+impl BungieClient {
+    /// Definitions keyed by stat id.
+    pub async fn historical_stats_definition(
+        &self,
+    ) -> Result<HashMap<String, DestinyHistoricalStatsDefinition>> {
+        self.get_bungie_response::<HashMap<String, DestinyHistoricalStatsDefinition>>(
+            self.url("Platform/Destiny2/Stats/Definition/"),
+        )
+        .await
+    }
+
+    pub async fn historical_stats_for_account(
+        &self,
+        membership_type: BungieMembershipType,
+        membership_id: MembershipId,
+        groups: &[DestinyStatsGroupType],
+    ) -> Result<DestinyHistoricalStatsAccountResult> {
+        let mut url = self.account_url(membership_type, membership_id);
+
+        url.path_segments_mut()
+            .expect("Cannot set path segments")
+            .push("Stats")
+            .push("");
+
+        if !groups.is_empty() {
+            url.query_pairs_mut()
+                .append_pair("groups", &join_groups(groups));
+        }
+
+        self.get_bungie_response::<DestinyHistoricalStatsAccountResult>(url)
+            .await
+    }
+
+    /// Results are keyed by activity mode name. A `CharacterId(0)` aggregates
+    /// every character on the account.
+    pub async fn historical_stats(
+        &self,
+        membership_type: BungieMembershipType,
+        membership_id: MembershipId,
+        character_id: CharacterId,
+        modes: &[DestinyActivityModeType],
+        period_type: Option<PeriodType>,
+        days: Option<(NaiveDate, NaiveDate)>,
+    ) -> Result<HashMap<String, DestinyHistoricalStatsByPeriod>> {
+        let mut url = self.character_url(membership_type, membership_id, character_id);
+
+        url.path_segments_mut()
+            .expect("Cannot set path segments")
+            .push("Stats")
+            .push("");
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+            if !modes.is_empty() {
+                query_pairs.append_pair("modes", &join_modes(modes));
+            }
+            if let Some(period_type) = period_type {
+                query_pairs.append_pair("periodType", &i32::from(period_type).to_string());
+            }
+            if let Some((day_start, day_end)) = days {
+                query_pairs.append_pair("daystart", &day_start.to_string());
+                query_pairs.append_pair("dayend", &day_end.to_string());
+            }
+        }
+
+        self.get_bungie_response::<HashMap<String, DestinyHistoricalStatsByPeriod>>(url)
+            .await
+    }
+
+    pub async fn aggregate_activity_stats(
+        &self,
+        membership_type: BungieMembershipType,
+        membership_id: MembershipId,
+        character_id: CharacterId,
+    ) -> Result<DestinyAggregateActivityResults> {
+        let mut url = self.character_url(membership_type, membership_id, character_id);
+
+        url.path_segments_mut()
+            .expect("Cannot set path segments")
+            .push("Stats")
+            .push("AggregateActivityStats")
+            .push("");
+
+        self.get_bungie_response::<DestinyAggregateActivityResults>(url)
+            .await
+    }
+
+    pub async fn unique_weapon_history(
+        &self,
+        membership_type: BungieMembershipType,
+        membership_id: MembershipId,
+        character_id: CharacterId,
+    ) -> Result<DestinyHistoricalWeaponStatsData> {
+        let mut url = self.character_url(membership_type, membership_id, character_id);
+
+        url.path_segments_mut()
+            .expect("Cannot set path segments")
+            .push("Stats")
+            .push("UniqueWeapons")
+            .push("");
+
+        self.get_bungie_response::<DestinyHistoricalWeaponStatsData>(url)
+            .await
+    }
+}
+
+impl BungieClient {
+    fn account_url(
+        &self,
+        membership_type: BungieMembershipType,
+        membership_id: MembershipId,
+    ) -> Url {
+        let mut url = self.url("Platform/Destiny2/");
+
+        url.path_segments_mut()
+            .expect("Cannot set path segments")
+            .pop_if_empty()
+            .push(&i32::from(membership_type).to_string())
+            .push("Account")
+            .push(&membership_id.to_string());
+
+        url
+    }
+
+    fn character_url(
+        &self,
+        membership_type: BungieMembershipType,
+        membership_id: MembershipId,
+        character_id: CharacterId,
+    ) -> Url {
+        let mut url = self.account_url(membership_type, membership_id);
+
+        url.path_segments_mut()
+            .expect("Cannot set path segments")
+            .push("Character")
+            .push(&character_id.to_string());
+
+        url
+    }
+}
+
+fn join_groups(groups: &[DestinyStatsGroupType]) -> String {
+    groups
+        .iter()
+        .copied()
+        .map(|g| i32::from(g).to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -7,6 +7,9 @@ pub enum Error {
     ClientError(Box<reqwest::Response>),
     ServerError(Box<reqwest::Response>),
     Bungie(crate::types::exceptions::PlatformErrorCodes),
+    ComponentUnavailable(crate::types::components::ComponentUnavailable),
+    /// The request could not be sent or its body not read, e.g. a connection reset or timeout.
+    Reqwest(reqwest::Error),
 
     SerdeJson(serde_json::Error),
 }
@@ -24,3 +27,15 @@ impl From<serde_json::Error> for Error {
         Error::SerdeJson(e)
     }
 }
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        Error::Reqwest(e)
+    }
+}
+
+impl From<crate::types::components::ComponentUnavailable> for Error {
+    fn from(e: crate::types::components::ComponentUnavailable) -> Self {
+        Error::ComponentUnavailable(e)
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+
+use crate::DestinyInventoryItemManifest;
+use crate::types::definitions::DestinyInventoryItemDefinition;
+use crate::types::destiny::DestinyItemType;
+use crate::types::destiny::components::items::DestinyItemReusablePlugsComponent;
+use crate::types::destiny::entities::inventory::DestinyInventoryComponent;
+use crate::types::destiny::entities::items::{
+    DestinyItemComponent, DestinyItemInstanceComponent, DestinyItemPerksComponent,
+    DestinyItemSocketsComponent, DestinyItemStatsComponent,
+};
+use crate::types::destiny::responses::DestinyProfileResponse;
+use crate::types::ids::{CharacterId, ItemInstanceId};
+use crate::types::{ItemLocation, TierType};
+
+/// Every item on a profile, joined with its definition and instance components. Components
+/// that were not requested are treated as empty.
+#[derive(Debug)]
+pub struct Inventory<'a> {
+    pub items: Vec<OwnedItem<'a>>,
+    pub(crate) item_definitions: &'a DestinyInventoryItemManifest,
+}
+
+#[derive(Debug)]
+pub struct OwnedItem<'a> {
+    pub item: &'a DestinyItemComponent,
+    pub owner: ItemOwner,
+    pub equipped: bool,
+    pub definition: Option<&'a DestinyInventoryItemDefinition>,
+    pub instance: Option<&'a DestinyItemInstanceComponent>,
+    pub stats: Option<&'a DestinyItemStatsComponent>,
+    pub sockets: Option<&'a DestinyItemSocketsComponent>,
+    /// The plugs each socket can switch to, keyed by socket index.
+    pub reusable_plugs: Option<&'a DestinyItemReusablePlugsComponent>,
+    pub perks: Option<&'a DestinyItemPerksComponent>,
+}
+
+/// Items in `profile_inventory` belong to the vault, including account-wide buckets such as
+/// consumables and mods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemOwner {
+    Vault,
+    Character(CharacterId),
+}
+
+impl<'a> Inventory<'a> {
+    pub fn new(
+        profile: &'a DestinyProfileResponse,
+        item_definitions: &'a DestinyInventoryItemManifest,
+    ) -> Self {
+        let components = profile.item_components.as_ref();
+        let instances = components.and_then(|c| c.instances.data().ok());
+        let stats = components.and_then(|c| c.stats.data().ok());
+        let sockets = components.and_then(|c| c.sockets.data().ok());
+        let reusable_plugs = components.and_then(|c| c.reusable_plugs.data().ok());
+        let perks = components.and_then(|c| c.perks.data().ok());
+
+        let mut held = Vec::new();
+        if let Ok(equipment) = profile.character_equipment() {
+            held.extend(by_character(equipment).map(|(id, c)| (ItemOwner::Character(id), c, true)));
+        }
+        if let Ok(inventories) = profile.character_inventories() {
+            held.extend(
+                by_character(inventories).map(|(id, c)| (ItemOwner::Character(id), c, false)),
+            );
+        }
+        if let Ok(vault) = profile.profile_inventory() {
+            held.push((ItemOwner::Vault, vault, false));
+        }
+
+        let items = held
+            .into_iter()
+            .flat_map(|(owner, inventory, equipped)| {
+                inventory.items.iter().map(move |item| {
+                    let id = item.item_instance_id;
+
+                    OwnedItem {
+                        item,
+                        owner,
+                        equipped,
+                        definition: item_definitions.get(&item.item_hash.to_string()),
+                        instance: component(instances, id),
+                        stats: component(stats, id),
+                        sockets: component(sockets, id),
+                        reusable_plugs: component(reusable_plugs, id),
+                        perks: component(perks, id),
+                    }
+                })
+            })
+            .collect();
+
+        Inventory {
+            items,
+            item_definitions,
+        }
+    }
+
+    pub fn get(&self, instance_id: ItemInstanceId) -> Option<&OwnedItem<'a>> {
+        self.items
+            .iter()
+            .find(|i| i.item.item_instance_id == Some(instance_id))
+    }
+
+    pub fn by_bucket(&self, bucket_hash: u32) -> impl Iterator<Item = &OwnedItem<'a>> {
+        self.items
+            .iter()
+            .filter(move |i| i.item.bucket_hash == bucket_hash)
+    }
+
+    /// Items without a definition never match.
+    pub fn by_item_type(&self, item_type: DestinyItemType) -> impl Iterator<Item = &OwnedItem<'a>> {
+        self.items
+            .iter()
+            .filter(move |i| i.definition.is_some_and(|d| d.item_type == item_type))
+    }
+
+    /// Items without a definition never match.
+    pub fn by_tier(&self, tier: TierType) -> impl Iterator<Item = &OwnedItem<'a>> {
+        self.items
+            .iter()
+            .filter(move |i| i.definition.is_some_and(|d| d.inventory.tier_type == tier))
+    }
+
+    pub fn by_location(&self, location: ItemLocation) -> impl Iterator<Item = &OwnedItem<'a>> {
+        self.items
+            .iter()
+            .filter(move |i| i.item.location == location)
+    }
+
+    pub fn by_owner(&self, owner: ItemOwner) -> impl Iterator<Item = &OwnedItem<'a>> {
+        self.items.iter().filter(move |i| i.owner == owner)
+    }
+}
+
+fn component<T>(
+    map: Option<&HashMap<ItemInstanceId, T>>,
+    instance_id: Option<ItemInstanceId>,
+) -> Option<&T> {
+    map.zip(instance_id).and_then(|(map, id)| map.get(&id))
+}
+
+/// Characters in ID order, so the item order is stable.
+fn by_character(
+    inventories: &HashMap<CharacterId, DestinyInventoryComponent>,
+) -> impl Iterator<Item = (CharacterId, &DestinyInventoryComponent)> {
+    let mut inventories = inventories
+        .iter()
+        .map(|(id, inventory)| (*id, inventory))
+        .collect::<Vec<_>>();
+    inventories.sort_by_key(|(id, _)| *id);
+    inventories.into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Inventory, ItemOwner};
+    use crate::DestinyInventoryItemManifest;
+    use crate::test_fixtures::{definitions, profile};
+    use crate::types::destiny::DestinyItemType;
+    use crate::types::destiny::responses::DestinyProfileResponse;
+    use crate::types::ids::{CharacterId, ItemInstanceId};
+    use crate::types::{ItemLocation, TierType};
+
+    fn fixtures() -> (DestinyProfileResponse, DestinyInventoryItemManifest) {
+        (
+            profile("profile.json"),
+            definitions("DestinyInventoryItemDefinition"),
+        )
+    }
+
+    #[test]
+    fn joins_components() {
+        let (profile, definitions) = fixtures();
+        let inventory = Inventory::new(&profile, &definitions);
+        assert_eq!(inventory.items.len(), 3);
+
+        let weapon = inventory.get(ItemInstanceId(6917529800000000001)).unwrap();
+        assert_eq!(
+            weapon.owner,
+            ItemOwner::Character(CharacterId(2305843009300000001))
+        );
+        assert!(weapon.equipped);
+        assert!(weapon.definition.is_some());
+        assert!(weapon.instance.unwrap().is_equipped);
+        assert_eq!(weapon.sockets.unwrap().sockets.len(), 2);
+
+        let vaulted = inventory.get(ItemInstanceId(6917529800000000003)).unwrap();
+        assert_eq!(vaulted.owner, ItemOwner::Vault);
+        assert!(!vaulted.equipped);
+    }
+
+    #[test]
+    fn queries() {
+        let (profile, definitions) = fixtures();
+        let inventory = Inventory::new(&profile, &definitions);
+
+        assert_eq!(inventory.by_bucket(953998645).count(), 1);
+        assert_eq!(inventory.by_location(ItemLocation::Vault).count(), 1);
+        assert_eq!(inventory.by_owner(ItemOwner::Vault).count(), 1);
+        assert_eq!(inventory.by_item_type(DestinyItemType::Weapon).count(), 2);
+        assert_eq!(inventory.by_item_type(DestinyItemType::Armor).count(), 1);
+        assert_eq!(inventory.by_tier(TierType::Exotic).count(), 1);
+        assert_eq!(inventory.by_tier(TierType::Superior).count(), 2);
+    }
+}
@@ -0,0 +1,84 @@
+use serde::de::DeserializeOwned;
+
+/// A field present in a response that the models do not know about.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownField {
+    /// The type that was being deserialized, e.g. `bungie_api::types::response::BungieResponse<…>`.
+    pub type_name: &'static str,
+    /// Dotted serde path to the field from the top of the response, e.g.
+    /// `Response.?.profile.?.data.?.newField`, where `?` marks an `Option` that was present.
+    pub path: String,
+}
+
+/// Deserializes `text`, skipping unknown fields and recording them in `unknown_fields` under the
+/// name of `T`.
+pub fn from_str<T: DeserializeOwned>(
+    text: &str,
+    unknown_fields: &mut Vec<UnknownField>,
+) -> serde_json::Result<T> {
+    let mut deserializer = serde_json::Deserializer::from_str(text);
+    let value = serde_ignored::deserialize(&mut deserializer, |path| {
+        unknown_fields.push(UnknownField {
+            type_name: std::any::type_name::<T>(),
+            path: path.to_string(),
+        })
+    })?;
+    deserializer.end()?;
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{UnknownField, from_str};
+    use crate::mock_server::{MockBungieServer, MockResponse};
+    use crate::test_fixtures::fixture;
+    use crate::types::destiny::config::DestinyManifest;
+    use crate::types::response::BungieResponse;
+
+    #[test]
+    fn records_paths() {
+        let mut manifest = fixture::<serde_json::Value>("manifest.json");
+        manifest["Response"]["newField"] = json!(1);
+        manifest["Response"]["jsonWorldComponentContentPaths"]["en"]["NewDefinition"] = json!("");
+        manifest["Surprise"] = json!(true);
+
+        let mut unknown_fields = Vec::new();
+        from_str::<BungieResponse<DestinyManifest>>(&manifest.to_string(), &mut unknown_fields)
+            .unwrap();
+        assert!(
+            unknown_fields
+                .iter()
+                .all(|f| f.type_name == std::any::type_name::<BungieResponse<DestinyManifest>>())
+        );
+        let mut paths = unknown_fields
+            .into_iter()
+            .map(|f| f.path)
+            .collect::<Vec<_>>();
+        paths.sort();
+        assert_eq!(paths, ["Response.?.newField", "Surprise"]);
+    }
+
+    #[tokio::test]
+    async fn client_collects_unknown_fields() {
+        let server = MockBungieServer::start().await;
+        let mut manifest = fixture::<serde_json::Value>("manifest.json");
+        manifest["Response"]["newField"] = json!(1);
+        server
+            .mount("/Manifest/", MockResponse::raw(manifest))
+            .await;
+
+        let client = server.client();
+        client.destiny_manifest().await.unwrap();
+        assert_eq!(
+            client.take_unknown_fields(),
+            [UnknownField {
+                type_name: std::any::type_name::<BungieResponse<DestinyManifest>>(),
+                path: String::from("Response.?.newField"),
+            }]
+        );
+        assert!(client.take_unknown_fields().is_empty());
+    }
+}
@@ -1,25 +1,57 @@
+pub mod armor_stats;
 mod bungie_client;
+pub mod crafting;
 
+pub mod duplicates;
 pub mod endpoints;
 mod error;
+pub mod inventory;
+#[cfg(feature = "lenient")]
+pub mod lenient;
+pub mod loadout;
+#[cfg(any(test, feature = "mock-server"))]
+pub mod mock_server;
+pub mod rolls;
+pub mod rotation;
+#[cfg(test)]
+mod schema_drift;
+pub mod search;
 pub mod serde_as;
+pub mod socket_layout;
+#[cfg(test)]
+mod test_fixtures;
+pub mod triumphs;
 use std::collections::HashMap;
 
 pub use error::Error;
 use error::Result;
 
 pub mod types;
+pub mod wishlist;
 
 pub use bungie_client::{BungieClient, BungieClientBuilder};
 pub use types::definitions::DestinyInventoryItemDefinition;
+pub use types::destiny::definitions::activities::{
+    DestinyActivityDefinition, DestinyActivityModifierDefinition,
+};
+pub use types::destiny::definitions::milestones::DestinyMilestoneDefinition;
+pub use types::destiny::definitions::presentation::DestinyPresentationNodeDefinition;
+pub use types::destiny::definitions::records::DestinyRecordDefinition;
 pub use types::destiny::definitions::sockets::{
     DestinyPlugSetDefinition, DestinySocketCategoryDefinition, DestinySocketTypeDefinition,
 };
+pub use types::destiny::definitions::vendors::DestinyVendorDefinition;
 
 pub type DestinyInventoryItemManifest = HashMap<String, DestinyInventoryItemDefinition>;
 pub type DestinyPlugSetManifest = HashMap<String, DestinyPlugSetDefinition>;
 pub type DestinySocketCategoryManifest = HashMap<String, DestinySocketCategoryDefinition>;
 pub type DestinySocketTypeManifest = HashMap<String, DestinySocketTypeDefinition>;
+pub type DestinyActivityManifest = HashMap<String, DestinyActivityDefinition>;
+pub type DestinyActivityModifierManifest = HashMap<String, DestinyActivityModifierDefinition>;
+pub type DestinyVendorManifest = HashMap<String, DestinyVendorDefinition>;
+pub type DestinyMilestoneManifest = HashMap<String, DestinyMilestoneDefinition>;
+pub type DestinyRecordManifest = HashMap<String, DestinyRecordDefinition>;
+pub type DestinyPresentationNodeManifest = HashMap<String, DestinyPresentationNodeDefinition>;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,701 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+use crate::DestinyInventoryItemManifest;
+use crate::armor_stats::{self, ArmorStatBreakdown, ArmorStats};
+use crate::inventory::{Inventory, OwnedItem};
+use crate::types::TierType;
+use crate::types::definitions::DestinyInventoryItemDefinition;
+use crate::types::destiny::{DestinyClass, DestinyItemType};
+
+pub const HELMET: u32 = 3448274439;
+pub const GAUNTLETS: u32 = 3551918588;
+pub const CHEST_ARMOR: u32 = 14239492;
+pub const LEG_ARMOR: u32 = 20886954;
+pub const CLASS_ARMOR: u32 = 1585787867;
+
+/// The armor bucket hashes, in the order [`Loadout::armor`] holds them.
+pub const ARMOR_SLOTS: [u32; 5] = [HELMET, GAUNTLETS, CHEST_ARMOR, LEG_ARMOR, CLASS_ARMOR];
+
+#[derive(Debug, Clone)]
+pub struct LoadoutRequest {
+    pub class: DestinyClass,
+    /// The lowest acceptable tier of each stat. See [`ArmorStats::tiers`].
+    pub min_tiers: ArmorStats,
+    /// Plug hashes of the stat mods that may be slotted, at most one per piece. Mods that
+    /// raise anything other than a single armor stat are ignored.
+    pub mods: Vec<u32>,
+    /// How many loadouts to return.
+    pub limit: usize,
+}
+
+#[derive(Debug)]
+pub struct Loadout<'i, 'a> {
+    /// One piece per slot, in [`ARMOR_SLOTS`] order.
+    pub armor: [&'i OwnedItem<'a>; 5],
+    /// The mod to slot into each piece in place of its current stat mods, beside any artifice
+    /// mod.
+    pub mods: [Option<u32>; 5],
+    /// The loadout's stats with masterworks and the chosen mods.
+    pub stats: ArmorStats,
+}
+
+/// Picks armor for `request.class` from the inventory that reaches the requested tiers with at
+/// most one exotic, best first. Loadouts rank by total tier, then stat total.
+///
+/// Each piece contributes its base and masterwork stats, read from the stats component, or
+/// from the definition's stat block when the component was not requested. An artifice mod
+/// keeps its own socket and its bonus; the other stat mods are removed and their energy freed.
+/// Mods are then chosen greedily per stat, preferring the smallest that covers what is
+/// missing, and fitted into pieces by remaining energy.
+/// Pieces that another piece in the same slot beats on every stat and energy are skipped, as
+/// are partial loadouts that cannot reach the targets or outscore the `limit`th best so far.
+pub fn optimize<'i, 'a>(
+    inventory: &'i Inventory<'a>,
+    request: &LoadoutRequest,
+) -> Vec<Loadout<'i, 'a>> {
+    let definitions = inventory.item_definitions;
+    let slots = ARMOR_SLOTS.map(|bucket| {
+        let mut pieces = prune(
+            inventory
+                .by_bucket(bucket)
+                .filter_map(|item| piece(item, request.class, definitions))
+                .collect(),
+        );
+        // Strong pieces first, so the best loadouts fill up early and bound the rest.
+        pieces.sort_by_key(|piece| Reverse(piece.stats.total()));
+        pieces
+    });
+    if request.limit == 0 || slots.iter().any(Vec::is_empty) {
+        return Vec::new();
+    }
+
+    // The most each slot onwards can add to each stat and to the stat total, for pruning the
+    // search.
+    let mut reach = [ArmorStats::default(); 6];
+    let mut reach_total = [0; 6];
+    for slot in (0..5).rev() {
+        let best = slots[slot]
+            .iter()
+            .fold(ArmorStats::default(), |best, piece| max(best, piece.stats));
+        reach[slot] = reach[slot + 1] + best;
+        let best_total = slots[slot].iter().map(|p| p.stats.total()).max();
+        reach_total[slot] = reach_total[slot + 1] + best_total.unwrap_or(0);
+    }
+
+    let mods = stat_mods(&request.mods, definitions);
+    let mut search = Search {
+        slots: &slots,
+        reach,
+        reach_total,
+        largest_mod: mods
+            .iter()
+            .filter_map(|m| m.first())
+            .map(|m| m.value)
+            .max()
+            .unwrap_or(0),
+        mods,
+        targets: request
+            .min_tiers
+            .iter()
+            .map(|(hash, tier)| (hash, tier * 10))
+            .collect(),
+        limit: request.limit,
+        best: BinaryHeap::new(),
+    };
+    search.visit(0, &mut [0; 5], ArmorStats::default(), false);
+
+    search
+        .best
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(ranked)| Loadout {
+            armor: std::array::from_fn(|slot| slots[slot][ranked.picks[slot]].item),
+            mods: ranked.mods,
+            stats: ranked.stats,
+        })
+        .collect()
+}
+
+impl Loadout<'_, '_> {
+    pub fn tiers(&self) -> ArmorStats {
+        self.stats.tiers()
+    }
+
+    pub fn total_tier(&self) -> i32 {
+        self.tiers().total()
+    }
+}
+
+struct Piece<'i, 'a> {
+    item: &'i OwnedItem<'a>,
+    stats: ArmorStats,
+    /// Energy left once the piece's stat mods are removed.
+    energy: i32,
+    exotic: bool,
+}
+
+struct StatMod {
+    plug_hash: u32,
+    value: i32,
+    energy_cost: i32,
+}
+
+#[derive(PartialEq, Eq)]
+struct Ranked {
+    score: (i32, i32),
+    picks: [usize; 5],
+    mods: [Option<u32>; 5],
+    /// Follows from `picks` and `mods`, so it takes no part in the ordering.
+    stats: ArmorStats,
+}
+
+impl Ord for Ranked {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.score, self.picks, self.mods).cmp(&(other.score, other.picks, other.mods))
+    }
+}
+
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+struct Search<'s, 'i, 'a> {
+    slots: &'s [Vec<Piece<'i, 'a>>; 5],
+    reach: [ArmorStats; 6],
+    reach_total: [i32; 6],
+    largest_mod: i32,
+    /// Usable mods per stat in [`armor_stats::ARMOR_STATS`] order, largest first.
+    mods: [Vec<StatMod>; 6],
+    targets: ArmorStats,
+    limit: usize,
+    /// The best loadouts so far, worst on top.
+    best: BinaryHeap<Reverse<Ranked>>,
+}
+
+impl Search<'_, '_, '_> {
+    fn visit(&mut self, slot: usize, picks: &mut [usize; 5], stats: ArmorStats, exotic: bool) {
+        if slot == 5 {
+            self.evaluate(picks, stats);
+            return;
+        }
+
+        let slots = self.slots;
+        for (index, piece) in slots[slot].iter().enumerate() {
+            if exotic && piece.exotic {
+                continue;
+            }
+            let stats = stats + piece.stats;
+            if !self.may_rank(slot, stats)
+                || self.choose_mods(stats + self.reach[slot + 1]).is_none()
+            {
+                continue;
+            }
+            picks[slot] = index;
+            self.visit(slot + 1, picks, stats, exotic || piece.exotic);
+        }
+    }
+
+    fn evaluate(&mut self, picks: &[usize; 5], stats: ArmorStats) {
+        let Some(chosen) = self.choose_mods(stats) else {
+            return;
+        };
+
+        // Costliest mod into the piece with the most energy, which fits whenever any
+        // assignment does.
+        let mut pieces = [0, 1, 2, 3, 4];
+        pieces.sort_by_key(|slot| Reverse(self.slots[*slot][picks[*slot]].energy));
+        let mut mods = [None; 5];
+        let mut total = stats;
+        for (slot, (stat, m)) in pieces.into_iter().zip(chosen.into_iter().flatten()) {
+            if m.energy_cost > self.slots[slot][picks[slot]].energy {
+                return;
+            }
+            mods[slot] = Some(m.plug_hash);
+            total = total + single(stat, m.value);
+        }
+
+        self.best.push(Reverse(Ranked {
+            score: (total.tiers().total(), total.total()),
+            picks: *picks,
+            mods,
+            stats: total,
+        }));
+        if self.best.len() > self.limit {
+            self.best.pop();
+        }
+    }
+
+    /// Whether a loadout completing `stats`, the pieces picked up to `slot`, could still make the
+    /// top `limit`. Ties are kept, since they may win on the order of their picks.
+    ///
+    /// Later pieces only raise stats, so the loadout needs no more mods than `stats` does, and a
+    /// mod of `n` points gains at most `n / 10` tiers, rounded up. No stat's tier exceeds what
+    /// the best remaining pieces reach, and the tiers together cannot exceed a tenth of the total.
+    fn may_rank(&self, slot: usize, stats: ArmorStats) -> bool {
+        let Some(Reverse(worst)) = self.best.peek().filter(|_| self.best.len() == self.limit)
+        else {
+            return true;
+        };
+        let mods = self
+            .choose_mods(stats)
+            .map_or(5, |chosen| chosen.iter().flatten().count());
+        let mods = i32::try_from(mods).unwrap();
+        let total = stats.total() + self.reach_total[slot + 1] + mods * self.largest_mod;
+        let tiers =
+            (stats + self.reach[slot + 1]).tiers().total() + mods * ((self.largest_mod + 9) / 10);
+        (tiers.min(total / 10), total) >= worst.score
+    }
+
+    /// The mods that lift `stats` to the targets, costliest first, or `None` when more than one
+    /// per piece would be needed.
+    fn choose_mods(&self, stats: ArmorStats) -> Option<[Option<(u32, &StatMod)>; 5]> {
+        let mut chosen = [None; 5];
+        let mut count = 0;
+        for ((stat, value), (options, (_, target))) in
+            stats.iter().zip(self.mods.iter().zip(self.targets.iter()))
+        {
+            let mut missing = target - value;
+            while missing > 0 {
+                let m = options
+                    .iter()
+                    .rev()
+                    .find(|m| m.value >= missing)
+                    .or(options.first())?;
+                *chosen.get_mut(count)? = Some((stat, m));
+                count += 1;
+                missing -= m.value;
+            }
+        }
+        chosen.sort_by_key(|c| Reverse(c.map(|(_, m)| m.energy_cost)));
+        Some(chosen)
+    }
+}
+
+fn piece<'i, 'a>(
+    item: &'i OwnedItem<'a>,
+    class: DestinyClass,
+    item_definitions: &DestinyInventoryItemManifest,
+) -> Option<Piece<'i, 'a>> {
+    let definition = item.definition?;
+    if definition.item_type != DestinyItemType::Armor || definition.class_type != class {
+        return None;
+    }
+
+    let plugs = item
+        .sockets
+        .into_iter()
+        .flat_map(|s| &s.sockets)
+        .filter(|s| s.is_enabled)
+        .filter_map(|s| item_definitions.get(&s.plug_hash.to_string()));
+    let (artifice, mods): (Vec<_>, Vec<_>) = plugs
+        .filter(|plug| armor_stats::is_mod(plug))
+        .partition(|plug| armor_stats::is_artifice(plug));
+
+    let stats = match ArmorStatBreakdown::new(item, item_definitions) {
+        Some(breakdown) => artifice
+            .iter()
+            .fold(breakdown.base + breakdown.masterwork, |stats, plug| {
+                stats + ArmorStats::from_plug(plug)
+            }),
+        None => definition
+            .stats
+            .as_ref()?
+            .stats
+            .values()
+            .map(|s| (s.stat_hash, s.value))
+            .collect(),
+    };
+
+    let freed = mods
+        .into_iter()
+        .filter(|plug| ArmorStats::from_plug(plug).total() != 0)
+        .map(energy_cost)
+        .sum::<i32>();
+    let energy = item
+        .instance
+        .and_then(|i| i.energy.as_ref())
+        .map_or(0, |e| e.energy_capacity - e.energy_used + freed);
+
+    Some(Piece {
+        item,
+        stats,
+        energy,
+        exotic: definition.inventory.tier_type == TierType::Exotic,
+    })
+}
+
+/// Drops pieces that another piece beats or ties on every stat and on energy, keeping the
+/// first of equal pieces. An exotic never stands in for a non-exotic.
+fn prune<'i, 'a>(pieces: Vec<Piece<'i, 'a>>) -> Vec<Piece<'i, 'a>> {
+    let covers = |a: &Piece, b: &Piece| {
+        a.energy >= b.energy
+            && (!a.exotic || b.exotic)
+            && a.stats
+                .iter()
+                .zip(b.stats.iter())
+                .all(|((_, x), (_, y))| x >= y)
+    };
+
+    let keep = pieces
+        .iter()
+        .enumerate()
+        .map(|(i, piece)| {
+            !pieces.iter().enumerate().any(|(j, other)| {
+                j != i && covers(other, piece) && (j < i || !covers(piece, other))
+            })
+        })
+        .collect::<Vec<_>>();
+    pieces
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(piece, keep)| keep.then_some(piece))
+        .collect()
+}
+
+fn stat_mods(mods: &[u32], item_definitions: &DestinyInventoryItemManifest) -> [Vec<StatMod>; 6] {
+    let mut by_stat: [Vec<StatMod>; 6] = Default::default();
+    for (plug_hash, plug) in mods
+        .iter()
+        .filter_map(|hash| Some((*hash, item_definitions.get(&hash.to_string())?)))
+    {
+        let mut raised = ArmorStats::from_plug(plug)
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, value))| *value != 0);
+        let (Some((stat, (_, value))), None) = (raised.next(), raised.next()) else {
+            continue;
+        };
+        if value > 0 {
+            by_stat[stat].push(StatMod {
+                plug_hash,
+                value,
+                energy_cost: energy_cost(plug),
+            });
+        }
+    }
+
+    // Largest first, and only the cheapest mod of each size.
+    for options in &mut by_stat {
+        options.sort_by_key(|m| (Reverse(m.value), m.energy_cost));
+        options.dedup_by_key(|m| m.value);
+    }
+    by_stat
+}
+
+fn energy_cost(plug: &DestinyInventoryItemDefinition) -> i32 {
+    plug.plug
+        .as_ref()
+        .and_then(|p| p.energy_cost.as_ref())
+        .map_or(0, |c| c.energy_cost)
+}
+
+fn max(a: ArmorStats, b: ArmorStats) -> ArmorStats {
+    a.iter()
+        .zip(b.iter())
+        .map(|((hash, x), (_, y))| (hash, x.max(y)))
+        .collect()
+}
+
+fn single(stat_hash: u32, value: i32) -> ArmorStats {
+    [(stat_hash, value)].into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::{
+        CHEST_ARMOR, CLASS_ARMOR, GAUNTLETS, HELMET, LEG_ARMOR, Loadout, LoadoutRequest, optimize,
+    };
+    use crate::DestinyInventoryItemManifest;
+    use crate::armor_stats::{ARMOR_STATS, ArmorStats};
+    use crate::inventory::Inventory;
+    use crate::test_fixtures::{
+        add_copy, component, definitions, fixture, parse_profile, set_plugs, set_stats,
+    };
+    use crate::types::TierType;
+    use crate::types::destiny::DestinyClass;
+    use crate::types::destiny::responses::DestinyProfileResponse;
+
+    const HELMET_ID: u64 = 6917529800000000002;
+    const MODS: [u32; 3] = [1703647492, 4204488676, 2263321587];
+    /// Discipline-Forged, an artifice mod worth 3 discipline.
+    const ARTIFICE_DISCIPLINE: u32 = 2000000012;
+
+    /// Adds a Warlock armor piece numbered `n` as a copy of the fixture helmet, with its own
+    /// definition, no mods and all of its energy free.
+    fn add_piece(
+        profile: &mut Value,
+        definitions: &mut Value,
+        n: u64,
+        bucket: u32,
+        exotic: bool,
+        stats: [i32; 6],
+    ) {
+        let id = (HELMET_ID - 2 + n).to_string();
+        let hash = 100000 + n;
+
+        let mut definition = definitions["3299386902"].clone();
+        definition["hash"] = hash.into();
+        definition["inventory"]["bucketTypeHash"] = bucket.into();
+        definition["inventory"]["tierType"] = if exotic { 6 } else { 5 }.into();
+        definitions[hash.to_string()] = definition;
+
+        let item = add_copy(profile, &HELMET_ID.to_string(), &id);
+        item["itemHash"] = hash.into();
+        item["bucketHash"] = bucket.into();
+        component(profile, "instances", &id)["energy"]["energyUsed"] = 0.into();
+        set_plugs(profile, &id, &[]);
+        set_stats(profile, &id, ARMOR_STATS.into_iter().zip(stats));
+    }
+
+    /// The fixture profile and definitions with a full set of Warlock armor around the
+    /// fixture helmet, an exotic helmet and an exotic chest. The class item has 2 energy free.
+    fn fixtures() -> (DestinyProfileResponse, DestinyInventoryItemManifest) {
+        let (profile, definitions) = warlock_armor();
+        (
+            parse_profile(profile),
+            serde_json::from_value(definitions).unwrap(),
+        )
+    }
+
+    /// The JSON behind [`fixtures`], for tests that add more pieces.
+    fn warlock_armor() -> (Value, Value) {
+        let mut profile: Value = fixture("profile.json");
+        let mut definitions: Value = definitions("DestinyInventoryItemDefinition");
+
+        for (n, bucket, exotic, stats) in [
+            (11, GAUNTLETS, false, [10; 6]),
+            (12, CHEST_ARMOR, false, [10; 6]),
+            (13, LEG_ARMOR, false, [10; 6]),
+            (14, CLASS_ARMOR, false, [10; 6]),
+            (15, CHEST_ARMOR, true, [5, 20, 20, 5, 5, 5]),
+            (16, HELMET, true, [5, 25, 25, 5, 5, 5]),
+        ] {
+            add_piece(&mut profile, &mut definitions, n, bucket, exotic, stats);
+        }
+        let class_item = (HELMET_ID + 12).to_string();
+        component(&mut profile, "instances", &class_item)["energy"]["energyUsed"] = 8.into();
+
+        (profile, definitions)
+    }
+
+    fn request(min_tiers: ArmorStats) -> LoadoutRequest {
+        LoadoutRequest {
+            class: DestinyClass::Warlock,
+            min_tiers,
+            mods: MODS.to_vec(),
+            limit: 10,
+        }
+    }
+
+    fn helmets_and_chests(loadouts: &[Loadout]) -> Vec<(u64, u64)> {
+        loadouts
+            .iter()
+            .map(|l| {
+                (
+                    l.armor[0].item.item_instance_id.unwrap().0,
+                    l.armor[2].item.item_instance_id.unwrap().0,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn ranks_loadouts() {
+        let (profile, definitions) = fixtures();
+        let inventory = Inventory::new(&profile, &definitions);
+
+        let loadouts = optimize(
+            &inventory,
+            &request(ArmorStats {
+                resilience: 7,
+                recovery: 6,
+                ..Default::default()
+            }),
+        );
+        assert_eq!(
+            helmets_and_chests(&loadouts),
+            [
+                (HELMET_ID, HELMET_ID + 10),
+                (HELMET_ID + 14, HELMET_ID + 10),
+                (HELMET_ID, HELMET_ID + 13),
+            ]
+        );
+        assert_eq!(
+            loadouts.iter().map(|l| l.total_tier()).collect::<Vec<_>>(),
+            [31, 29, 28]
+        );
+
+        let best = &loadouts[0];
+        assert_eq!((best.stats.resilience, best.stats.recovery), (70, 60));
+        assert_eq!(best.mods.iter().flatten().count(), 2);
+        assert_eq!(best.mods[4], None);
+
+        let impossible = optimize(
+            &inventory,
+            &request(ArmorStats {
+                mobility: 10,
+                ..Default::default()
+            }),
+        );
+        assert!(impossible.is_empty());
+    }
+
+    #[test]
+    fn respects_energy() {
+        let (profile, definitions) = fixtures();
+        let inventory = Inventory::new(&profile, &definitions);
+
+        // Without an exotic this needs five mods, and the class item has no room for one.
+        let loadouts = optimize(
+            &inventory,
+            &request(ArmorStats {
+                resilience: 9,
+                recovery: 7,
+                ..Default::default()
+            }),
+        );
+        assert_eq!(
+            helmets_and_chests(&loadouts),
+            [
+                (HELMET_ID + 14, HELMET_ID + 10),
+                (HELMET_ID, HELMET_ID + 13)
+            ]
+        );
+    }
+
+    /// Artifice gauntlets with 8 discipline and the artifice mod's 3 replace the plain pair, which
+    /// would beat them if the artifice bonus were stripped with the other mods.
+    #[test]
+    fn keeps_artifice_bonuses() {
+        let request = LoadoutRequest {
+            mods: Vec::new(),
+            ..request(ArmorStats::default())
+        };
+        let (profile, definitions) = fixtures();
+        let inventory = Inventory::new(&profile, &definitions);
+        let plain = optimize(&inventory, &request);
+
+        let (mut profile, mut definitions) = warlock_armor();
+        add_piece(
+            &mut profile,
+            &mut definitions,
+            17,
+            GAUNTLETS,
+            false,
+            [10, 10, 10, 11, 10, 10],
+        );
+        set_plugs(
+            &mut profile,
+            &(HELMET_ID + 15).to_string(),
+            &[ARTIFICE_DISCIPLINE],
+        );
+        let profile = parse_profile(profile);
+        let definitions = serde_json::from_value(definitions).unwrap();
+        let inventory = Inventory::new(&profile, &definitions);
+        let artifice = optimize(&inventory, &request);
+
+        assert_eq!(artifice.len(), plain.len());
+        for (artifice, plain) in artifice.iter().zip(&plain) {
+            assert_eq!(
+                plain.armor[1].item.item_instance_id.unwrap().0,
+                HELMET_ID + 9
+            );
+            assert_eq!(
+                artifice.armor[1].item.item_instance_id.unwrap().0,
+                HELMET_ID + 15
+            );
+            assert_eq!(
+                artifice.stats - plain.stats,
+                ArmorStats {
+                    discipline: 1,
+                    ..ArmorStats::default()
+                }
+            );
+            assert_eq!(artifice.mods, [None; 5]);
+        }
+    }
+
+    /// A vault's worth of armor: 20 pieces per slot with rolled stats, one in ten exotic.
+    #[test]
+    fn searches_a_full_vault() {
+        let mut profile: Value = fixture("profile.json");
+        let mut definitions: Value = definitions("DestinyInventoryItemDefinition");
+        let mut seed = 1u64;
+        let mut roll = |bound: u64| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            i32::try_from((seed >> 33) % bound).unwrap()
+        };
+        for (slot, bucket) in [HELMET, GAUNTLETS, CHEST_ARMOR, LEG_ARMOR, CLASS_ARMOR]
+            .into_iter()
+            .enumerate()
+        {
+            for n in 0..20 {
+                // Each group of three stats shares 30 to 34 points, at least 2 each.
+                let mut stats = [0; 6];
+                for group in [0, 3] {
+                    let total = 30 + roll(5);
+                    let first = 2 + roll(u64::try_from(total - 5).unwrap());
+                    let second = 2 + roll(u64::try_from(total - first - 3).unwrap());
+                    stats[group] = first;
+                    stats[group + 1] = second;
+                    stats[group + 2] = total - first - second;
+                }
+                let n = 100 + 20 * slot as u64 + n;
+                add_piece(
+                    &mut profile,
+                    &mut definitions,
+                    n,
+                    bucket,
+                    n.is_multiple_of(10),
+                    stats,
+                );
+            }
+        }
+        let profile = parse_profile(profile);
+        let definitions = serde_json::from_value(definitions).unwrap();
+        let inventory = Inventory::new(&profile, &definitions);
+
+        let mut request = request(ArmorStats {
+            resilience: 8,
+            recovery: 7,
+            discipline: 5,
+            ..Default::default()
+        });
+        let loadouts = optimize(&inventory, &request);
+        assert_eq!(loadouts.len(), 10);
+        for loadout in &loadouts {
+            let tiers = loadout.tiers();
+            assert!(tiers.resilience >= 8 && tiers.recovery >= 7 && tiers.discipline >= 5);
+            let exotics = loadout
+                .armor
+                .iter()
+                .filter(|piece| piece.definition.unwrap().inventory.tier_type == TierType::Exotic)
+                .count();
+            assert!(exotics <= 1);
+        }
+        let scores = loadouts
+            .iter()
+            .map(|l| (l.total_tier(), l.stats.total()))
+            .collect::<Vec<_>>();
+        assert!(scores.is_sorted_by(|a, b| a >= b));
+
+        // A larger limit bounds the search less tightly but must agree on the best ten.
+        request.limit = 50;
+        let more = optimize(&inventory, &request);
+        assert_eq!(more.len(), 50);
+        let ids = |loadouts: &[Loadout]| {
+            loadouts
+                .iter()
+                .map(|l| (l.armor.map(|piece| piece.item.item_instance_id), l.mods))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(&loadouts), ids(&more[..10]));
+    }
+}
@@ -0,0 +1,540 @@
+//! A local stand-in for bungie.net that serves the fixture corpus, for testing against
+//! [`BungieClient`] without network access or an API key.
+
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::{Value, json};
+use url::Url;
+use wiremock::matchers::{method, path, path_regex};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::{BungieClient, BungieClientBuilder};
+
+const MANIFEST: &str = include_str!("../tests/fixtures/manifest.json");
+/// Route regexes and the fixture served on each.
+const ROUTES: [(&str, &str); 15] = [
+    (r"^/Platform/Destiny2/Manifest/$", MANIFEST),
+    (
+        r"^/Platform/Destiny2/SearchDestinyPlayer/-?\d+/[^/]+/$",
+        include_str!("../tests/fixtures/search_destiny_player.json"),
+    ),
+    (
+        r"^/Platform/Destiny2/-?\d+/Profile/\d+/$",
+        include_str!("../tests/fixtures/profile.json"),
+    ),
+    (
+        r"^/Platform/Destiny2/-?\d+/Account/\d+/Stats/$",
+        include_str!("../tests/fixtures/historical_stats_account.json"),
+    ),
+    (
+        r"^/Platform/Destiny2/-?\d+/Account/\d+/Character/\d+/Stats/$",
+        include_str!("../tests/fixtures/historical_stats.json"),
+    ),
+    (
+        r"^/Platform/Destiny2/-?\d+/Account/\d+/Character/\d+/Stats/Activities/$",
+        include_str!("../tests/fixtures/activity_history.json"),
+    ),
+    (
+        r"^/Platform/Destiny2/-?\d+/Account/\d+/Character/\d+/Stats/AggregateActivityStats/$",
+        include_str!("../tests/fixtures/aggregate_activity_stats.json"),
+    ),
+    (
+        r"^/Platform/Destiny2/-?\d+/Account/\d+/Character/\d+/Stats/UniqueWeapons/$",
+        include_str!("../tests/fixtures/unique_weapons.json"),
+    ),
+    (
+        r"^/Platform/Destiny2/Stats/Definition/$",
+        include_str!("../tests/fixtures/historical_stats_definition.json"),
+    ),
+    (
+        r"^/Platform/Destiny2/Stats/PostGameCarnageReport/\d+/$",
+        include_str!("../tests/fixtures/pgcr.json"),
+    ),
+    (
+        r"^/Platform/Destiny2/Stats/AggregateClanStats/\d+/$",
+        include_str!("../tests/fixtures/clan_aggregate_stats.json"),
+    ),
+    (
+        r"^/Platform/Destiny2/Stats/Leaderboards/Clans/\d+/$",
+        include_str!("../tests/fixtures/clan_leaderboards.json"),
+    ),
+    (
+        r"^/Platform/Destiny2/Clan/\d+/WeeklyRewardState/$",
+        include_str!("../tests/fixtures/clan_weekly_reward_state.json"),
+    ),
+    (
+        r"^/Platform/Destiny2/Milestones/$",
+        include_str!("../tests/fixtures/milestones.json"),
+    ),
+    (
+        r"^/Platform/Destiny2/Milestones/\d+/Content/$",
+        include_str!("../tests/fixtures/milestone_content.json"),
+    ),
+];
+const DEFINITIONS: [(&str, &str); 10] = [
+    (
+        "DestinyActivityDefinition",
+        include_str!("../tests/fixtures/definitions/DestinyActivityDefinition.json"),
+    ),
+    (
+        "DestinyActivityModifierDefinition",
+        include_str!("../tests/fixtures/definitions/DestinyActivityModifierDefinition.json"),
+    ),
+    (
+        "DestinyInventoryItemDefinition",
+        include_str!("../tests/fixtures/definitions/DestinyInventoryItemDefinition.json"),
+    ),
+    (
+        "DestinyMilestoneDefinition",
+        include_str!("../tests/fixtures/definitions/DestinyMilestoneDefinition.json"),
+    ),
+    (
+        "DestinyPlugSetDefinition",
+        include_str!("../tests/fixtures/definitions/DestinyPlugSetDefinition.json"),
+    ),
+    (
+        "DestinyPresentationNodeDefinition",
+        include_str!("../tests/fixtures/definitions/DestinyPresentationNodeDefinition.json"),
+    ),
+    (
+        "DestinyRecordDefinition",
+        include_str!("../tests/fixtures/definitions/DestinyRecordDefinition.json"),
+    ),
+    (
+        "DestinySocketCategoryDefinition",
+        include_str!("../tests/fixtures/definitions/DestinySocketCategoryDefinition.json"),
+    ),
+    (
+        "DestinySocketTypeDefinition",
+        include_str!("../tests/fixtures/definitions/DestinySocketTypeDefinition.json"),
+    ),
+    (
+        "DestinyVendorDefinition",
+        include_str!("../tests/fixtures/definitions/DestinyVendorDefinition.json"),
+    ),
+];
+
+/// Routes mounted by [`MockBungieServer::mount`] take precedence over the fixtures, which are
+/// mounted at wiremock's default priority of 5.
+const OVERRIDE_PRIORITY: u8 = 1;
+
+pub struct MockBungieServer {
+    server: MockServer,
+}
+
+impl MockBungieServer {
+    /// Starts a server with a fixture mounted on every `Destiny2/...` route the crate calls,
+    /// plus the manifest's `en` definitions.
+    pub async fn start() -> Self {
+        let server = Self::empty().await;
+
+        for (route, fixture) in ROUTES {
+            server.mount_fixture(route, fixture).await;
+        }
+
+        let manifest = serde_json::from_str::<Value>(MANIFEST).unwrap();
+        for (definition, body) in DEFINITIONS {
+            let content_path = manifest["Response"]["jsonWorldComponentContentPaths"]["en"]
+                [definition]
+                .as_str()
+                .unwrap();
+
+            Mock::given(method("GET"))
+                .and(path(content_path))
+                .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+                .mount(&server.server)
+                .await;
+        }
+
+        server
+    }
+
+    /// Starts a server with nothing mounted. Unmatched requests get a 404.
+    pub async fn empty() -> Self {
+        Self {
+            server: MockServer::start().await,
+        }
+    }
+
+    pub fn base_url(&self) -> Url {
+        Url::parse(&self.server.uri()).unwrap()
+    }
+
+    /// A client that sends its requests to this server.
+    pub fn client(&self) -> BungieClient {
+        BungieClientBuilder::new("")
+            .base_url(self.base_url())
+            .build()
+            .unwrap()
+    }
+
+    /// Serves `response` for GET requests whose path matches the `route` regex, in place of any
+    /// fixture on the same route.
+    pub async fn mount(&self, route: &str, response: MockResponse) {
+        Mock::given(method("GET"))
+            .and(path_regex(route))
+            .respond_with(response.into_template())
+            .with_priority(OVERRIDE_PRIORITY)
+            .mount(&self.server)
+            .await;
+    }
+
+    async fn mount_fixture(&self, route: &str, fixture: &str) {
+        Mock::given(method("GET"))
+            .and(path_regex(route))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(fixture, "application/json"))
+            .mount(&self.server)
+            .await;
+    }
+}
+
+/// A response for [`MockBungieServer::mount`], built from a successful envelope and then
+/// degraded as needed.
+pub struct MockResponse {
+    body: Value,
+    status: u16,
+    content_type: String,
+    delay: Duration,
+}
+
+impl MockResponse {
+    /// A `Success` envelope around `response`.
+    pub fn ok(response: impl Serialize) -> Self {
+        Self::raw(json!({
+            "Response": response,
+            "ErrorCode": 1,
+            "ThrottleSeconds": 0,
+            "ErrorStatus": "Success",
+            "Message": "Ok",
+            "MessageData": {},
+        }))
+    }
+
+    /// A body served as-is, without an envelope.
+    pub fn raw(body: Value) -> Self {
+        Self {
+            body,
+            status: 200,
+            content_type: String::from("application/json; charset=utf-8"),
+            delay: Duration::ZERO,
+        }
+    }
+
+    /// Replaces the envelope's error code. Bungie leaves out `Response` on errors, so this
+    /// does too.
+    pub fn error_code(mut self, error_code: u32, error_status: &str) -> Self {
+        if let Value::Object(envelope) = &mut self.body {
+            envelope.remove("Response");
+            envelope.insert("ErrorCode".into(), error_code.into());
+            envelope.insert("ErrorStatus".into(), error_status.into());
+            envelope.insert("Message".into(), error_status.into());
+        }
+        self
+    }
+
+    pub fn throttle_seconds(mut self, throttle_seconds: i32) -> Self {
+        if let Value::Object(envelope) = &mut self.body {
+            envelope.insert("ThrottleSeconds".into(), throttle_seconds.into());
+        }
+        self
+    }
+
+    /// The HTTP status, e.g. a 503 during maintenance.
+    pub fn status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Anything other than `application/json` is rejected by the client before parsing.
+    pub fn content_type(mut self, content_type: &str) -> Self {
+        self.content_type = content_type.to_owned();
+        self
+    }
+
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    fn into_template(self) -> ResponseTemplate {
+        ResponseTemplate::new(self.status)
+            .set_body_raw(self.body.to_string(), &self.content_type)
+            .set_delay(self.delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use futures::StreamExt;
+    use serde_json::Value;
+
+    use super::{MockBungieServer, MockResponse};
+    use crate::types::BungieMembershipType;
+    use crate::types::destiny::DestinyComponentType;
+    use crate::types::exceptions::PlatformErrorCodes;
+    use crate::types::ids::{ActivityInstanceId, MembershipId};
+    use crate::types::response::BungieResponse;
+    use crate::{BungieClientBuilder, Error};
+
+    const PROFILE_ROUTE: &str = r"/Profile/";
+
+    #[tokio::test]
+    async fn serves_fixtures() {
+        let server = MockBungieServer::start().await;
+        let client = server.client();
+
+        let manifest = client.destiny_manifest().await.unwrap();
+        let items = client
+            .destiny_inventory_item_definition(&manifest, "en")
+            .await
+            .unwrap();
+        assert!(items.contains_key("1363886209"));
+        client
+            .destiny_socket_type_definition(&manifest, "en")
+            .await
+            .unwrap();
+        client
+            .destiny_socket_category_definition(&manifest, "en")
+            .await
+            .unwrap();
+        client
+            .destiny_plug_set_definition(&manifest, "en")
+            .await
+            .unwrap();
+        client
+            .destiny_activity_definition(&manifest, "en")
+            .await
+            .unwrap();
+        client
+            .destiny_activity_modifier_definition(&manifest, "en")
+            .await
+            .unwrap();
+        client
+            .destiny_vendor_definition(&manifest, "en")
+            .await
+            .unwrap();
+        client
+            .destiny_milestone_definition(&manifest, "en")
+            .await
+            .unwrap();
+        client
+            .destiny_record_definition(&manifest, "en")
+            .await
+            .unwrap();
+        client
+            .destiny_presentation_node_definition(&manifest, "en")
+            .await
+            .unwrap();
+
+        let players = client
+            .search_destiny_player("Sample Guardian", 1234)
+            .await
+            .unwrap();
+        assert_eq!(players[0].membership_id, MembershipId(4611686018400000001));
+
+        let profile = client
+            .profile(
+                BungieMembershipType::TigerSteam,
+                MembershipId(4611686018400000001),
+                &[DestinyComponentType::Profiles],
+            )
+            .await
+            .unwrap();
+        let character_id = profile.profile().unwrap().character_ids[0];
+
+        client
+            .activity_history(
+                BungieMembershipType::TigerSteam,
+                MembershipId(4611686018400000001),
+                character_id,
+                None,
+                None,
+                0,
+            )
+            .await
+            .unwrap();
+        client
+            .post_game_carnage_report(ActivityInstanceId(14020000001))
+            .await
+            .unwrap();
+
+        let membership_id = MembershipId(4611686018400000001);
+        client.historical_stats_definition().await.unwrap();
+        client
+            .historical_stats_for_account(BungieMembershipType::TigerSteam, membership_id, &[])
+            .await
+            .unwrap();
+        client
+            .historical_stats(
+                BungieMembershipType::TigerSteam,
+                membership_id,
+                character_id,
+                &[],
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        client
+            .aggregate_activity_stats(
+                BungieMembershipType::TigerSteam,
+                membership_id,
+                character_id,
+            )
+            .await
+            .unwrap();
+        client
+            .unique_weapon_history(
+                BungieMembershipType::TigerSteam,
+                membership_id,
+                character_id,
+            )
+            .await
+            .unwrap();
+
+        client.clan_weekly_reward_state(4000000).await.unwrap();
+        client.clan_aggregate_stats(4000000, &[]).await.unwrap();
+        client
+            .clan_leaderboards(4000000, &[], Some(10), None)
+            .await
+            .unwrap();
+
+        let milestones = client.public_milestones().await.unwrap();
+        client
+            .public_milestone_content(*milestones.keys().next().unwrap())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn injects_error_code() {
+        let server = MockBungieServer::start().await;
+        server
+            .mount(
+                PROFILE_ROUTE,
+                MockResponse::ok(()).error_code(5, "SystemDisabled"),
+            )
+            .await;
+
+        let result = server
+            .client()
+            .profile(BungieMembershipType::TigerSteam, MembershipId(1), &[])
+            .await;
+        assert!(matches!(
+            result,
+            Err(Error::Bungie(PlatformErrorCodes::Unknown(5)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn injects_throttle_seconds() {
+        let server = MockBungieServer::empty().await;
+        server
+            .mount(
+                "/Manifest/",
+                MockResponse::ok(Value::Null).throttle_seconds(10),
+            )
+            .await;
+
+        let client = server.client();
+        let url = server
+            .base_url()
+            .join("Platform/Destiny2/Manifest/")
+            .unwrap();
+        let response = client.get::<BungieResponse<Value>>(url).await.unwrap();
+        assert_eq!(response.throttle_seconds, 10);
+    }
+
+    #[tokio::test]
+    async fn injects_server_error() {
+        let server = MockBungieServer::start().await;
+        server
+            .mount(PROFILE_ROUTE, MockResponse::ok(()).status(503))
+            .await;
+
+        let result = server
+            .client()
+            .profile(BungieMembershipType::TigerSteam, MembershipId(1), &[])
+            .await;
+        assert!(matches!(result, Err(Error::ServerError(_))));
+    }
+
+    #[tokio::test]
+    async fn injects_content_type() {
+        let server = MockBungieServer::start().await;
+        server
+            .mount(
+                PROFILE_ROUTE,
+                MockResponse::ok(()).content_type("text/html"),
+            )
+            .await;
+
+        let result = server
+            .client()
+            .profile(BungieMembershipType::TigerSteam, MembershipId(1), &[])
+            .await;
+        assert!(matches!(result, Err(Error::InvalidContentType(_))));
+    }
+
+    #[tokio::test]
+    async fn transport_error() {
+        // Nothing listens on a port that was just released.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/", listener.local_addr().unwrap());
+        drop(listener);
+        let client = BungieClientBuilder::new("")
+            .base_url(base_url.parse().unwrap())
+            .build()
+            .unwrap();
+
+        let result = client.destiny_manifest().await;
+        assert!(matches!(result, Err(Error::Reqwest(_))));
+    }
+
+    #[tokio::test]
+    async fn pgcr_batch_contains_failures() {
+        let server = MockBungieServer::start().await;
+        server
+            .mount(
+                r"/PostGameCarnageReport/2/$",
+                MockResponse::ok(()).status(503),
+            )
+            .await;
+
+        let ids = [1, 2, 1, 3].map(ActivityInstanceId);
+        let mut results = server
+            .client()
+            .post_game_carnage_report_many(ids, 2)
+            .map(|(id, result)| (id.0, result.is_ok()))
+            .collect::<Vec<_>>()
+            .await;
+        results.sort_unstable();
+        assert_eq!(results, [(1, true), (2, false), (3, true)]);
+
+        let requests = server.server.received_requests().await.unwrap();
+        let pgcrs = requests
+            .iter()
+            .filter(|r| r.url.path().contains("/PostGameCarnageReport/"))
+            .count();
+        assert_eq!(pgcrs, 3);
+    }
+
+    #[tokio::test]
+    async fn injects_delay() {
+        let server = MockBungieServer::start().await;
+        let delay = Duration::from_millis(200);
+        server
+            .mount(
+                "/Manifest/",
+                MockResponse::raw(serde_json::from_str(super::MANIFEST).unwrap()).delay(delay),
+            )
+            .await;
+
+        let start = Instant::now();
+        server.client().destiny_manifest().await.unwrap();
+        assert!(start.elapsed() >= delay);
+    }
+}
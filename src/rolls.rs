@@ -0,0 +1,205 @@
+use std::fmt;
+
+use crate::types::definitions::DestinyInventoryItemDefinition;
+use crate::types::destiny::DestinyItemType;
+use crate::types::destiny::definitions::DestinyPlugItemCraftingRequirements;
+use crate::{DestinyInventoryItemManifest, DestinyPlugSetManifest};
+
+/// Every perk each random-roll column of an item can have.
+#[derive(Debug)]
+pub struct RollSheet<'a> {
+    pub definition: &'a DestinyInventoryItemDefinition,
+    pub columns: Vec<RollColumn<'a>>,
+}
+
+#[derive(Debug)]
+pub struct RollColumn<'a> {
+    pub socket_index: usize,
+    pub plug_set_hash: u32,
+    pub perks: Vec<PossiblePerk<'a>>,
+}
+
+#[derive(Debug)]
+pub struct PossiblePerk<'a> {
+    pub plug_item_hash: u32,
+    pub definition: Option<&'a DestinyInventoryItemDefinition>,
+    /// `false` for retired perks, which only appear on old copies or through crafting.
+    pub currently_can_roll: bool,
+    pub crafting_requirements: Option<&'a DestinyPlugItemCraftingRequirements>,
+}
+
+impl<'a> RollSheet<'a> {
+    /// Sockets with a randomized plug set become columns. Crafting patterns list their options
+    /// in reusable plug sets instead, so those are used for them. Other items can carry a
+    /// crafting block too, so only the item type marks a pattern. Returns `None` for items
+    /// without sockets.
+    pub fn new(
+        definition: &'a DestinyInventoryItemDefinition,
+        item_definitions: &'a DestinyInventoryItemManifest,
+        plug_set_definitions: &'a DestinyPlugSetManifest,
+    ) -> Option<Self> {
+        let pattern = definition.item_type == DestinyItemType::Pattern;
+        let columns = definition
+            .sockets
+            .as_ref()?
+            .socket_entries
+            .iter()
+            .enumerate()
+            .filter_map(|(socket_index, entry)| {
+                let plug_set_hash = entry
+                    .randomized_plug_set_hash
+                    .or(entry.reusable_plug_set_hash.filter(|_| pattern))?;
+                let plug_set = plug_set_definitions.get(&plug_set_hash.to_string())?;
+
+                let mut perks = Vec::<PossiblePerk>::new();
+                for plug in &plug_set.reusable_plug_items {
+                    // Plug sets can list a perk more than once, e.g. at different weights.
+                    if let Some(perk) = perks
+                        .iter_mut()
+                        .find(|p| p.plug_item_hash == plug.plug_item_hash)
+                    {
+                        perk.currently_can_roll |= plug.currently_can_roll;
+                        continue;
+                    }
+                    perks.push(PossiblePerk {
+                        plug_item_hash: plug.plug_item_hash,
+                        definition: item_definitions.get(&plug.plug_item_hash.to_string()),
+                        currently_can_roll: plug.currently_can_roll,
+                        crafting_requirements: plug.crafting_requirements.as_ref(),
+                    });
+                }
+
+                Some(RollColumn {
+                    socket_index,
+                    plug_set_hash,
+                    perks,
+                })
+            })
+            .collect();
+
+        Some(RollSheet {
+            definition,
+            columns,
+        })
+    }
+
+    /// The number of perks across every column, retired ones included.
+    pub fn perk_count(&self) -> usize {
+        self.columns.iter().map(|c| c.perks.len()).sum()
+    }
+
+    /// The number of distinct rolls a new drop can have.
+    pub fn combinations(&self) -> u64 {
+        self.count(PossiblePerk::can_roll)
+    }
+
+    /// The number of distinct rolls that can be crafted at weapon `level`.
+    pub fn crafted_combinations(&self, level: i32) -> u64 {
+        self.count(|perk| perk.craftable_at(level))
+    }
+
+    fn count(&self, available: impl Fn(&PossiblePerk<'a>) -> bool) -> u64 {
+        self.columns
+            .iter()
+            .map(|c| c.perks.iter().filter(|p| available(p)).count() as u64)
+            .product()
+    }
+}
+
+impl PossiblePerk<'_> {
+    pub fn can_roll(&self) -> bool {
+        self.currently_can_roll
+    }
+
+    /// Whether the perk can be chosen when crafting at weapon `level`. Perks without crafting
+    /// requirements cannot be crafted.
+    pub fn craftable_at(&self, level: i32) -> bool {
+        self.crafting_requirements
+            .is_some_and(|r| r.required_level.unwrap_or_default() <= level)
+    }
+
+    fn name(&self) -> String {
+        match self.definition {
+            Some(d) => d.display_properties.name.clone(),
+            None => self.plug_item_hash.to_string(),
+        }
+    }
+}
+
+/// The item name, then one line per column, e.g. `2: Rampage, Kill Clip (level 4)`. Retired
+/// perks are marked.
+impl fmt::Display for RollSheet<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.definition.display_properties.name)?;
+        for (n, column) in self.columns.iter().enumerate() {
+            let perks = column
+                .perks
+                .iter()
+                .map(|perk| {
+                    let mut name = perk.name();
+                    if let Some(level) = perk
+                        .crafting_requirements
+                        .and_then(|r| r.required_level)
+                        .filter(|level| *level > 0)
+                    {
+                        name.push_str(&format!(" (level {level})"));
+                    }
+                    if !perk.currently_can_roll {
+                        name.push_str(" (retired)");
+                    }
+                    name
+                })
+                .collect::<Vec<_>>();
+            writeln!(f, "{}: {}", n + 1, perks.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RollSheet;
+    use crate::test_fixtures::definitions;
+    use crate::{DestinyInventoryItemManifest, DestinyPlugSetManifest};
+
+    #[test]
+    fn roll_sheet() {
+        let items: DestinyInventoryItemManifest = definitions("DestinyInventoryItemDefinition");
+        let plug_sets: DestinyPlugSetManifest = definitions("DestinyPlugSetDefinition");
+
+        // A perk listed twice counts once, and still rolls if either entry does.
+        let rifle = RollSheet::new(&items["2000000021"], &items, &plug_sets).unwrap();
+        assert_eq!(rifle.columns.len(), 2);
+        assert_eq!(rifle.columns[0].plug_set_hash, 1050000004);
+        assert_eq!(rifle.perk_count(), 5);
+        assert_eq!(rifle.combinations(), 4);
+        assert_eq!(rifle.crafted_combinations(10), 0);
+        assert_eq!(
+            rifle.to_string(),
+            "Sample Pulse Rifle\n\
+             1: Sample Barrel, 839105230, 1467527085 (retired)\n\
+             2: Sample Magazine, 1885400500\n"
+        );
+
+        // The pattern has no randomized plug sets; its reusable ones carry the requirements.
+        let recipe = RollSheet::new(&items["3746717895"], &items, &plug_sets).unwrap();
+        assert_eq!(recipe.columns.len(), 2);
+        assert_eq!(recipe.perk_count(), 5);
+        assert_eq!(recipe.combinations(), 4);
+        assert_eq!(recipe.crafted_combinations(0), 3);
+        assert_eq!(recipe.crafted_combinations(4), 6);
+        assert_eq!(
+            recipe.to_string(),
+            "Sample Hand Cannon\n\
+             1: Outlaw, 2779035018, 3400784728 (retired)\n\
+             2: Rampage, 1015611457 (level 4)\n"
+        );
+
+        // The helmet has a crafting block but is no pattern, so its reusable armor mod plug set,
+        // whose entries have crafting requirements, is not a column.
+        assert!(items["3299386902"].crafting.is_some());
+        let helmet = RollSheet::new(&items["3299386902"], &items, &plug_sets).unwrap();
+        assert!(helmet.columns.is_empty());
+        assert_eq!(helmet.combinations(), 1);
+    }
+}
@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use crate::types::destiny::definitions::activities::{
+    DestinyActivityDefinition, DestinyActivityModifierDefinition,
+};
+use crate::types::destiny::definitions::milestones::DestinyMilestoneDefinition;
+use crate::types::destiny::definitions::vendors::DestinyVendorDefinition;
+use crate::types::destiny::milestones::DestinyPublicMilestone;
+use crate::{
+    DestinyActivityManifest, DestinyActivityModifierManifest, DestinyMilestoneManifest,
+    DestinyVendorManifest,
+};
+
+/// The public milestones for the current week, resolved against their definitions.
+#[derive(Debug)]
+pub struct WeeklyRotation<'a> {
+    pub milestones: Vec<RotationMilestone<'a>>,
+}
+
+#[derive(Debug)]
+pub struct RotationMilestone<'a> {
+    pub milestone: &'a DestinyPublicMilestone,
+    pub definition: Option<&'a DestinyMilestoneDefinition>,
+    pub activities: Vec<RotationActivity<'a>>,
+    pub vendors: Vec<&'a DestinyVendorDefinition>,
+}
+
+#[derive(Debug)]
+pub struct RotationActivity<'a> {
+    pub activity_hash: u32,
+    pub definition: Option<&'a DestinyActivityDefinition>,
+    pub modifiers: Vec<&'a DestinyActivityModifierDefinition>,
+}
+
+impl<'a> WeeklyRotation<'a> {
+    pub fn new(
+        milestones: &'a HashMap<u32, DestinyPublicMilestone>,
+        milestone_definitions: &'a DestinyMilestoneManifest,
+        activity_definitions: &'a DestinyActivityManifest,
+        modifier_definitions: &'a DestinyActivityModifierManifest,
+        vendor_definitions: &'a DestinyVendorManifest,
+    ) -> Self {
+        let mut milestones = milestones
+            .values()
+            .map(|milestone| {
+                let mut activities: Vec<RotationActivity> = Vec::new();
+
+                let quest_activities = milestone
+                    .available_quests
+                    .iter()
+                    .filter_map(|q| q.activity.as_ref())
+                    .map(|a| (a.activity_hash, &a.modifier_hashes));
+                let challenge_activities = milestone
+                    .activities
+                    .iter()
+                    .map(|a| (a.activity_hash, &a.modifier_hashes));
+
+                for (activity_hash, modifier_hashes) in quest_activities.chain(challenge_activities)
+                {
+                    if activities.iter().any(|a| a.activity_hash == activity_hash) {
+                        continue;
+                    }
+
+                    activities.push(RotationActivity {
+                        activity_hash,
+                        definition: activity_definitions.get(&activity_hash.to_string()),
+                        modifiers: modifier_hashes
+                            .iter()
+                            .filter_map(|hash| modifier_definitions.get(&hash.to_string()))
+                            .collect(),
+                    });
+                }
+
+                let mut vendor_hashes = milestone
+                    .vendors
+                    .iter()
+                    .map(|v| v.vendor_hash)
+                    .collect::<Vec<_>>();
+                for &hash in &milestone.vendor_hashes {
+                    if !vendor_hashes.contains(&hash) {
+                        vendor_hashes.push(hash);
+                    }
+                }
+
+                let vendors = vendor_hashes
+                    .iter()
+                    .filter_map(|hash| vendor_definitions.get(&hash.to_string()))
+                    .collect();
+
+                RotationMilestone {
+                    milestone,
+                    definition: milestone_definitions.get(&milestone.milestone_hash.to_string()),
+                    activities,
+                    vendors,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        milestones.sort_by_key(|m| m.milestone.order);
+
+        WeeklyRotation { milestones }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WeeklyRotation;
+    use crate::mock_server::MockBungieServer;
+    use crate::types::destiny::historical_stats::definitions::DestinyActivityModeType;
+
+    #[tokio::test]
+    async fn resolves_public_milestones() {
+        let server = MockBungieServer::start().await;
+        let client = server.client();
+        let manifest = client.destiny_manifest().await.unwrap();
+        let milestones = client.public_milestones().await.unwrap();
+        let milestone_definitions = client
+            .destiny_milestone_definition(&manifest, "en")
+            .await
+            .unwrap();
+        let activity_definitions = client
+            .destiny_activity_definition(&manifest, "en")
+            .await
+            .unwrap();
+        let modifier_definitions = client
+            .destiny_activity_modifier_definition(&manifest, "en")
+            .await
+            .unwrap();
+        let vendor_definitions = client
+            .destiny_vendor_definition(&manifest, "en")
+            .await
+            .unwrap();
+
+        let rotation = WeeklyRotation::new(
+            &milestones,
+            &milestone_definitions,
+            &activity_definitions,
+            &modifier_definitions,
+            &vendor_definitions,
+        );
+
+        let names = rotation
+            .milestones
+            .iter()
+            .map(|m| m.definition.unwrap().display_properties.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["Clan Rewards", "Sample Weekly"]);
+        assert_eq!(
+            rotation.milestones[0].vendors[0].display_properties.name,
+            "Xûr"
+        );
+
+        let weekly = &rotation.milestones[1];
+        let activities = weekly
+            .activities
+            .iter()
+            .map(|a| a.activity_hash)
+            .collect::<Vec<_>>();
+        assert_eq!(activities, [2591737171, 1600000001]);
+        assert_eq!(
+            weekly.activities[1].modifiers[0].display_properties.name,
+            "Sample Champions"
+        );
+        assert_eq!(
+            weekly.activities[1]
+                .definition
+                .unwrap()
+                .direct_activity_mode_type,
+            Some(DestinyActivityModeType::ScoredNightfall)
+        );
+        assert_eq!(weekly.vendors[0].hash, 2190858386);
+    }
+}
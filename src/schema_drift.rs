@@ -0,0 +1,1006 @@
+//! Compares the serde models against a local copy of Bungie's `openapi.json`.
+//!
+//! The models are traced with a probing [`Deserializer`] that walks down to one field at a time,
+//! so struct layouts, field shapes and accepted enum values are read straight from the derived
+//! `Deserialize` impls. The checker itself is tested against a small synthetic spec. The check of
+//! the real models is ignored by default: run it with `cargo test schema_drift -- --ignored`,
+//! which reads the spec from `$BUNGIE_OPENAPI`, falling back to `openapi.json` in the crate root,
+//! and fails when neither exists.
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt;
+
+use serde::de::value::StrDeserializer;
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, SeqAccess,
+    Visitor,
+};
+use serde_json::Value;
+
+use crate::types::FallbackEnum;
+
+#[derive(Debug)]
+enum ProbeError {
+    Abort,
+    MissingField,
+    Custom(String),
+}
+
+impl fmt::Display for ProbeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProbeError::Abort => f.write_str("probe aborted"),
+            ProbeError::MissingField => f.write_str("missing field"),
+            ProbeError::Custom(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl std::error::Error for ProbeError {}
+
+impl de::Error for ProbeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        ProbeError::Custom(msg.to_string())
+    }
+
+    fn missing_field(_field: &'static str) -> Self {
+        ProbeError::MissingField
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Leaf {
+    Bool,
+    I8,
+    I16,
+    I32,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64,
+    F32,
+    F64,
+    Str,
+    Struct(&'static str),
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Layer {
+    Option,
+    Seq,
+    Map,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Step {
+    Field(&'static str),
+    Some,
+    Element,
+    MapValue,
+}
+
+#[derive(Clone, Copy)]
+enum Mode {
+    /// Record the name and fields of the struct at the end of the path.
+    Fields,
+    /// Record the layers and leaf of the value at the end of the path.
+    Shape,
+    /// Feed an integer to the value at the end of the path.
+    Value(i64),
+}
+
+#[derive(Default)]
+struct Outcome {
+    name: Option<&'static str>,
+    fields: &'static [&'static str],
+    layers: Vec<Layer>,
+    leaf: Option<Leaf>,
+}
+
+struct Probe<'a> {
+    path: &'a [Step],
+    mode: Mode,
+    out: &'a RefCell<Outcome>,
+}
+
+impl<'a> Probe<'a> {
+    fn rest(&self) -> Probe<'a> {
+        Probe {
+            path: &self.path[1..],
+            mode: self.mode,
+            out: self.out,
+        }
+    }
+
+    fn leaf<'de, V: Visitor<'de>>(self, leaf: Leaf, visitor: V) -> Result<V::Value, ProbeError> {
+        if !self.path.is_empty() {
+            return Err(ProbeError::Abort);
+        }
+
+        match self.mode {
+            Mode::Shape => {
+                self.out.borrow_mut().leaf = Some(leaf);
+                Err(ProbeError::Abort)
+            }
+            Mode::Value(v) if leaf == Leaf::Str => visitor.visit_str(&v.to_string()),
+            Mode::Value(v) => visitor.visit_i64(v),
+            Mode::Fields => Err(ProbeError::Abort),
+        }
+    }
+}
+
+macro_rules! probe_leaf {
+    ($($method:ident => $leaf:expr,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ProbeError> {
+                self.leaf($leaf, visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for Probe<'_> {
+    type Error = ProbeError;
+
+    probe_leaf! {
+        deserialize_any => Leaf::Other,
+        deserialize_bool => Leaf::Bool,
+        deserialize_i8 => Leaf::I8,
+        deserialize_i16 => Leaf::I16,
+        deserialize_i32 => Leaf::I32,
+        deserialize_i64 => Leaf::I64,
+        deserialize_u8 => Leaf::U8,
+        deserialize_u16 => Leaf::U16,
+        deserialize_u32 => Leaf::U32,
+        deserialize_u64 => Leaf::U64,
+        deserialize_f32 => Leaf::F32,
+        deserialize_f64 => Leaf::F64,
+        deserialize_char => Leaf::Str,
+        deserialize_str => Leaf::Str,
+        deserialize_string => Leaf::Str,
+        deserialize_bytes => Leaf::Other,
+        deserialize_byte_buf => Leaf::Other,
+        deserialize_unit => Leaf::Other,
+        deserialize_identifier => Leaf::Other,
+        deserialize_ignored_any => Leaf::Other,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ProbeError> {
+        match self.path.first() {
+            Some(Step::Some) => visitor.visit_some(self.rest()),
+            Some(_) => Err(ProbeError::Abort),
+            None => {
+                if let Mode::Shape = self.mode {
+                    self.out.borrow_mut().layers.push(Layer::Option);
+                }
+                visitor.visit_some(self)
+            }
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ProbeError> {
+        match self.path.first() {
+            Some(Step::Element) => visitor.visit_seq(OneElement(Some(self.rest()))),
+            Some(_) => Err(ProbeError::Abort),
+            None => match self.mode {
+                Mode::Shape => {
+                    self.out.borrow_mut().layers.push(Layer::Seq);
+                    visitor.visit_seq(OneElement(Some(self)))
+                }
+                _ => Err(ProbeError::Abort),
+            },
+        }
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ProbeError> {
+        match self.path.first() {
+            Some(Step::MapValue) => visitor.visit_map(OneEntry(Some(self.rest()))),
+            Some(_) => Err(ProbeError::Abort),
+            None => match self.mode {
+                Mode::Shape => {
+                    self.out.borrow_mut().layers.push(Layer::Map);
+                    visitor.visit_map(OneEntry(Some(self)))
+                }
+                _ => Err(ProbeError::Abort),
+            },
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ProbeError> {
+        match self.path.first() {
+            Some(Step::Field(field)) => visitor.visit_map(OneField {
+                field: Some(field),
+                value: Some(self.rest()),
+            }),
+            Some(_) => Err(ProbeError::Abort),
+            None => {
+                let mut out = self.out.borrow_mut();
+                match self.mode {
+                    Mode::Fields => {
+                        out.name = Some(name);
+                        out.fields = fields;
+                    }
+                    Mode::Shape => out.leaf = Some(Leaf::Struct(name)),
+                    Mode::Value(_) => {}
+                }
+                Err(ProbeError::Abort)
+            }
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ProbeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ProbeError> {
+        self.leaf(Leaf::Other, visitor)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, ProbeError> {
+        self.leaf(Leaf::Other, visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, ProbeError> {
+        self.leaf(Leaf::Other, visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ProbeError> {
+        self.leaf(Leaf::Other, visitor)
+    }
+}
+
+struct OneElement<'a>(Option<Probe<'a>>);
+
+impl<'de> SeqAccess<'de> for OneElement<'_> {
+    type Error = ProbeError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, ProbeError> {
+        self.0
+            .take()
+            .map(|probe| seed.deserialize(probe))
+            .transpose()
+    }
+}
+
+struct OneEntry<'a>(Option<Probe<'a>>);
+
+impl<'de> MapAccess<'de> for OneEntry<'_> {
+    type Error = ProbeError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, ProbeError> {
+        if self.0.is_none() {
+            return Ok(None);
+        }
+        seed.deserialize(KeyProbe).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, ProbeError> {
+        seed.deserialize(self.0.take().unwrap())
+    }
+}
+
+struct OneField<'a> {
+    field: Option<&'static str>,
+    value: Option<Probe<'a>>,
+}
+
+impl<'de> MapAccess<'de> for OneField<'_> {
+    type Error = ProbeError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, ProbeError> {
+        self.field
+            .take()
+            .map(|field| {
+                let de: StrDeserializer<ProbeError> = field.into_deserializer();
+                seed.deserialize(de)
+            })
+            .transpose()
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, ProbeError> {
+        seed.deserialize(self.value.take().unwrap())
+    }
+}
+
+/// Supplies a zero-like key so the value of a map entry can be probed.
+struct KeyProbe;
+
+impl<'de> Deserializer<'de> for KeyProbe {
+    type Error = ProbeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ProbeError> {
+        visitor.visit_u64(0)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ProbeError> {
+        visitor.visit_str("0")
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ProbeError> {
+        visitor.visit_str("0")
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char bytes byte_buf option unit
+        unit_struct newtype_struct seq tuple tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+fn probe<T: DeserializeOwned>(path: &[Step], mode: Mode) -> (Outcome, Result<T, ProbeError>) {
+    let out = RefCell::new(Outcome::default());
+    let result = T::deserialize(Probe {
+        path,
+        mode,
+        out: &out,
+    });
+    (out.into_inner(), result)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Drift {
+    MissingStruct {
+        schema: String,
+        path: String,
+    },
+    MissingField {
+        schema: String,
+        field: String,
+    },
+    ExtraField {
+        rust_type: &'static str,
+        field: &'static str,
+    },
+    UnhandledEnumValue {
+        schema: String,
+        value: i64,
+        identifier: String,
+    },
+    TypeMismatch {
+        path: String,
+        expected: String,
+        found: String,
+    },
+    /// An enum that accepted every value it was fed, which fallback variants always do, and that
+    /// was not checked with [`DriftDetector::check_enum`].
+    UncheckedEnum {
+        schema: String,
+        path: String,
+    },
+}
+
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Drift::MissingStruct { schema, path } => {
+                write!(f, "missing struct for {schema} at {path}")
+            }
+            Drift::MissingField { schema, field } => write!(f, "{schema} is missing `{field}`"),
+            Drift::ExtraField { rust_type, field } => {
+                write!(f, "{rust_type} has `{field}` which is not in the spec")
+            }
+            Drift::UnhandledEnumValue {
+                schema,
+                value,
+                identifier,
+            } => write!(f, "{schema} does not handle {identifier} = {value}"),
+            Drift::TypeMismatch {
+                path,
+                expected,
+                found,
+            } => write!(f, "{path}: spec has {expected}, model has {found}"),
+            Drift::UncheckedEnum { schema, path } => {
+                write!(f, "{path} uses {schema}, which has no check_enum")
+            }
+        }
+    }
+}
+
+pub struct DriftDetector<'a> {
+    schemas: &'a serde_json::Map<String, Value>,
+    visited: HashSet<String>,
+    checked_enums: HashSet<String>,
+    /// Enum schemas reached from a struct that accepted every value, with the first path found.
+    enum_uses: Vec<(String, String)>,
+    drift: Vec<Drift>,
+}
+
+impl<'a> DriftDetector<'a> {
+    pub fn new(spec: &'a Value) -> Self {
+        DriftDetector {
+            schemas: spec["components"]["schemas"].as_object().unwrap(),
+            visited: HashSet::new(),
+            checked_enums: HashSet::new(),
+            enum_uses: Vec::new(),
+            drift: Vec::new(),
+        }
+    }
+
+    /// The differences found, including enums used by the checked structs that were never
+    /// passed to [`check_enum`](Self::check_enum).
+    pub fn finish(mut self) -> Vec<Drift> {
+        for (schema, path) in self.enum_uses {
+            if !self.checked_enums.contains(&schema) {
+                self.drift.push(Drift::UncheckedEnum { schema, path });
+            }
+        }
+        self.drift
+    }
+
+    pub fn check_struct<T: DeserializeOwned>(&mut self, schema: &str) {
+        if !self.schemas.contains_key(schema) {
+            self.drift.push(Drift::MissingStruct {
+                schema: schema.to_string(),
+                path: std::any::type_name::<T>().to_string(),
+            });
+            return;
+        }
+        self.walk::<T>(&mut Vec::new(), schema);
+    }
+
+    /// Reports the spec's values that `T` rejects or only keeps in its fallback variant.
+    pub fn check_enum<T: DeserializeOwned + FallbackEnum>(&mut self, schema: &str) {
+        if !self.schemas.contains_key(schema) {
+            self.drift.push(Drift::MissingStruct {
+                schema: schema.to_string(),
+                path: std::any::type_name::<T>().to_string(),
+            });
+            return;
+        }
+        if !self.checked_enums.insert(schema.to_string()) {
+            return;
+        }
+
+        for (value, identifier) in self.enum_values(schema) {
+            let handled = serde_json::from_value::<T>(Value::from(value))
+                .is_ok_and(|value| !value.is_unrecognized());
+            if !handled {
+                self.drift.push(Drift::UnhandledEnumValue {
+                    schema: schema.to_string(),
+                    value,
+                    identifier,
+                });
+            }
+        }
+    }
+
+    fn walk<T: DeserializeOwned>(&mut self, path: &mut Vec<Step>, schema_name: &str) {
+        if !self.visited.insert(schema_name.to_string()) {
+            return;
+        }
+
+        let (outcome, _) = probe::<T>(path, Mode::Fields);
+        let Some(rust_type) = outcome.name else {
+            return;
+        };
+
+        let schemas = self.schemas;
+        let properties = schemas[schema_name]["properties"]
+            .as_object()
+            .cloned()
+            .unwrap_or_default();
+
+        for property in properties.keys() {
+            if !outcome.fields.contains(&property.as_str()) {
+                self.drift.push(Drift::MissingField {
+                    schema: schema_name.to_string(),
+                    field: property.clone(),
+                });
+            }
+        }
+
+        for &field in outcome.fields {
+            let Some(property) = properties.get(field) else {
+                self.drift.push(Drift::ExtraField { rust_type, field });
+                continue;
+            };
+
+            path.push(Step::Field(field));
+            let (shape, _) = probe::<T>(path, Mode::Shape);
+            self.compare::<T>(path, &shape.layers, shape.leaf, property);
+            path.pop();
+        }
+    }
+
+    fn compare<T: DeserializeOwned>(
+        &mut self,
+        path: &mut Vec<Step>,
+        layers: &[Layer],
+        leaf: Option<Leaf>,
+        property: &Value,
+    ) {
+        if let Some((Layer::Option, rest)) = layers.split_first() {
+            path.push(Step::Some);
+            self.compare::<T>(path, rest, leaf, property);
+            path.pop();
+            return;
+        }
+
+        let (reference, property) = self.resolve(property);
+
+        match (layers.first(), property["type"].as_str()) {
+            (Some(Layer::Seq), Some("array")) => {
+                path.push(Step::Element);
+                self.compare::<T>(path, &layers[1..], leaf, &property["items"]);
+                path.pop();
+            }
+            (Some(Layer::Map), Some("object"))
+                if property.get("additionalProperties").is_some() =>
+            {
+                path.push(Step::MapValue);
+                let value = &property["additionalProperties"];
+                self.compare::<T>(path, &layers[1..], leaf, value);
+                path.pop();
+            }
+            (None, _) if property.get("enum").is_some() => {
+                if let Some(reference) = reference {
+                    self.check_enum_at::<T>(path, &reference, property);
+                }
+            }
+            (None, _) if property.get("properties").is_some() => match (leaf, reference) {
+                (Some(Leaf::Struct(_)), Some(reference)) => self.walk::<T>(path, &reference),
+                (_, reference) => self.drift.push(Drift::MissingStruct {
+                    schema: reference.unwrap_or_else(|| "inline object".to_string()),
+                    path: display_path(path),
+                }),
+            },
+            (None, Some(spec_type)) => {
+                let format = property["format"].as_str().unwrap_or_default();
+                if let Some(leaf) = leaf
+                    && !compatible(spec_type, format, leaf)
+                {
+                    self.drift.push(Drift::TypeMismatch {
+                        path: display_path(path),
+                        expected: format!("{spec_type} {format}").trim().to_string(),
+                        found: format!("{leaf:?}"),
+                    });
+                }
+            }
+            _ => self.drift.push(Drift::TypeMismatch {
+                path: display_path(path),
+                expected: property["type"].as_str().unwrap_or("object").to_string(),
+                found: format!("{layers:?} {leaf:?}"),
+            }),
+        }
+    }
+
+    /// Enums are only seen as integers from the enclosing struct, so a fallback variant cannot be
+    /// told apart from a known value here. Rejected values are reported, and enums that accept
+    /// every value are left to [`check_enum`](Self::check_enum).
+    fn check_enum_at<T: DeserializeOwned>(
+        &mut self,
+        path: &[Step],
+        reference: &str,
+        property: &Value,
+    ) {
+        if property["x-enum-is-bitmask"].as_bool().unwrap_or(false)
+            || !self.visited.insert(reference.to_string())
+        {
+            return;
+        }
+
+        let mut accepted_all = true;
+        for (value, identifier) in self.enum_values(reference) {
+            let (_, result) = probe::<T>(path, Mode::Value(value));
+            if let Err(ProbeError::Custom(_)) = result {
+                accepted_all = false;
+                self.drift.push(Drift::UnhandledEnumValue {
+                    schema: reference.to_string(),
+                    value,
+                    identifier,
+                });
+            }
+        }
+        if accepted_all {
+            self.enum_uses
+                .push((reference.to_string(), display_path(path)));
+        }
+    }
+
+    fn enum_values(&self, schema: &str) -> Vec<(i64, String)> {
+        self.schemas[schema]["x-enum-values"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|v| {
+                let value = match &v["numericValue"] {
+                    Value::String(s) => s.parse().ok()?,
+                    value => value.as_i64()?,
+                };
+                let identifier = v["identifier"].as_str().unwrap_or_default().to_string();
+                Some((value, identifier))
+            })
+            .collect()
+    }
+
+    /// Follows `$ref`, `x-enum-reference` and single-entry `allOf` wrappers to the schema they
+    /// point at.
+    fn resolve<'v>(&self, property: &'v Value) -> (Option<String>, &'v Value)
+    where
+        'a: 'v,
+    {
+        let target = property["allOf"]
+            .as_array()
+            .and_then(|all| all.first())
+            .unwrap_or(property);
+
+        let reference = target["$ref"]
+            .as_str()
+            .or_else(|| target["x-enum-reference"]["$ref"].as_str());
+
+        match reference {
+            Some(reference) => {
+                let name = reference.trim_start_matches("#/components/schemas/");
+                (
+                    Some(name.to_string()),
+                    self.schemas.get(name).unwrap_or(&Value::Null),
+                )
+            }
+            None => (None, target),
+        }
+    }
+}
+
+fn compatible(spec_type: &str, format: &str, leaf: Leaf) -> bool {
+    match (spec_type, format) {
+        ("boolean", _) => leaf == Leaf::Bool,
+        ("string", _) => leaf == Leaf::Str,
+        ("integer", "byte") => leaf == Leaf::U8,
+        ("integer", "int16") => leaf == Leaf::I16,
+        ("integer", "uint16") => leaf == Leaf::U16,
+        ("integer", "int32") => leaf == Leaf::I32,
+        ("integer", "uint32") => leaf == Leaf::U32,
+        // Bungie sends 64-bit integers as strings.
+        ("integer", "int64" | "uint64") => matches!(leaf, Leaf::I64 | Leaf::U64 | Leaf::Str),
+        ("number", "float") => leaf == Leaf::F32,
+        ("number", "double") => leaf == Leaf::F64,
+        _ => true,
+    }
+}
+
+fn display_path(path: &[Step]) -> String {
+    path.iter()
+        .map(|step| match step {
+            Step::Field(field) => field,
+            Step::Some => "?",
+            Step::Element => "[]",
+            Step::MapValue => "{}",
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    use serde::{Deserialize, Deserializer, de};
+    use serde_json::Value;
+
+    use super::DriftDetector;
+    use crate::test_fixtures::fixture;
+    use crate::types::BungieMembershipType;
+    use crate::types::FallbackEnum;
+    use crate::types::components::ComponentPrivacySetting;
+    use crate::types::definitions::DestinyInventoryItemDefinition;
+    use crate::types::destiny::config::DestinyManifest;
+    use crate::types::destiny::definitions::activities::{
+        DestinyActivityDefinition, DestinyActivityModifierDefinition,
+    };
+    use crate::types::destiny::definitions::milestones::DestinyMilestoneDefinition;
+    use crate::types::destiny::definitions::presentation::DestinyPresentationNodeDefinition;
+    use crate::types::destiny::definitions::records::DestinyRecordDefinition;
+    use crate::types::destiny::definitions::sockets::{
+        DestinyPlugSetDefinition, DestinySocketCategoryDefinition, DestinySocketTypeDefinition,
+    };
+    use crate::types::destiny::definitions::vendors::DestinyVendorDefinition;
+    use crate::types::destiny::historical_stats::definitions::{
+        DestinyActivityModeType, DestinyHistoricalStatsDefinition, DestinyStatsCategoryType,
+        DestinyStatsGroupType, DestinyStatsMergeMethods, PeriodType, UnitType,
+    };
+    use crate::types::destiny::historical_stats::{
+        DestinyActivityHistoryResults, DestinyAggregateActivityResults, DestinyClanAggregateStat,
+        DestinyHistoricalStatsAccountResult, DestinyHistoricalWeaponStatsData, DestinyLeaderboard,
+        DestinyPostGameCarnageReportData,
+    };
+    use crate::types::destiny::milestones::{
+        DestinyMilestone, DestinyMilestoneContent, DestinyPublicMilestone,
+    };
+    use crate::types::destiny::responses::DestinyProfileResponse;
+    use crate::types::destiny::{
+        BucketCategory, BucketScope, DamageType, DestinyActivityDifficultyTier,
+        DestinyAmmunitionType, DestinyBreakerType, DestinyClass, DestinyComponentType,
+        DestinyEnergyType, DestinyGamePrivacySetting, DestinyGender, DestinyItemSubType,
+        DestinyItemType, DestinyPresentationDisplayStyle, DestinyPresentationNodeType,
+        DestinyPresentationScreenStyle, DestinyProgressionRewardItemAcquisitionBehavior,
+        DestinyProgressionScope, DestinyProgressionStepDisplayEffect, DestinyRace,
+        DestinyRecordToastStyle, DestinyRecordValueStyle, DestinyScope, DestinySocketCategoryStyle,
+        DestinySocketVisibility, DestinyStatAggregationType, DestinyStatCategory,
+        DestinyTalentNodeState, DestinyVendorItemRefundPolicy, ItemBindStatus, ItemLocation,
+        ItemPerkVisibility, PlugAvailabilityMode, SocketTypeActionType, SpecialItemType, TierType,
+    };
+    use crate::types::user::UserInfoCard;
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    #[allow(dead_code)]
+    struct Item {
+        hash: u32,
+        name: String,
+        count: i64,
+        kind: Kind,
+        tier: Tier,
+        mode: i32,
+        child: Option<Child>,
+        children: Vec<Child>,
+        children_by_hash: HashMap<u32, Child>,
+        stats: String,
+        extra: bool,
+    }
+
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    struct Child {
+        value: i64,
+    }
+
+    #[allow(dead_code)]
+    enum Kind {
+        None,
+        First,
+        Unrecognized(u8),
+    }
+
+    impl<'de> Deserialize<'de> for Kind {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            match u8::deserialize(deserializer)? {
+                0 => Ok(Kind::None),
+                1 => Ok(Kind::First),
+                value => Ok(Kind::Unrecognized(value)),
+            }
+        }
+    }
+
+    impl FallbackEnum for Kind {
+        fn is_unrecognized(&self) -> bool {
+            matches!(self, Kind::Unrecognized(_))
+        }
+    }
+
+    struct Tier;
+
+    impl<'de> Deserialize<'de> for Tier {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            match u8::deserialize(deserializer)? {
+                0 => Ok(Tier),
+                value => Err(de::Error::custom(format!("unknown tier {value}"))),
+            }
+        }
+    }
+
+    #[test]
+    fn detects_drift() {
+        let spec: Value = fixture("openapi_sample.json");
+
+        let mut detector = DriftDetector::new(&spec);
+        detector.check_struct::<Item>("Sample.Item");
+        detector.check_struct::<Child>("Sample.Missing");
+        detector.check_enum::<Kind>("Sample.Kind");
+
+        let mut drift = detector
+            .finish()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        drift.sort();
+        assert_eq!(
+            drift,
+            [
+                "Item has `extra` which is not in the spec",
+                "Sample.Item is missing `removedInModel`",
+                "Sample.Kind does not handle Second = 2",
+                "Sample.Tier does not handle Superior = 1",
+                "count: spec has integer int32, model has I64",
+                "missing struct for Sample.Missing at bungie_api::schema_drift::tests::Child",
+                "missing struct for Sample.Stats at stats",
+                "mode uses Sample.Mode, which has no check_enum",
+            ]
+        );
+    }
+
+    #[test]
+    #[ignore = "needs Bungie's openapi.json, see the module docs"]
+    fn schema_drift() {
+        let path = std::env::var_os("BUNGIE_OPENAPI")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("openapi.json"));
+
+        let text = std::fs::read_to_string(&path).unwrap_or_else(|e| {
+            panic!(
+                "cannot read {}: {e}; set BUNGIE_OPENAPI to a copy of Bungie's spec",
+                path.display()
+            )
+        });
+        let spec: Value = serde_json::from_str(&text).unwrap();
+
+        let mut detector = DriftDetector::new(&spec);
+
+        detector.check_struct::<DestinyProfileResponse>("Destiny.Responses.DestinyProfileResponse");
+        detector.check_struct::<DestinyManifest>("Destiny.Config.DestinyManifest");
+        detector.check_struct::<UserInfoCard>("User.UserInfoCard");
+        detector.check_struct::<DestinyPostGameCarnageReportData>(
+            "Destiny.HistoricalStats.DestinyPostGameCarnageReportData",
+        );
+        detector.check_struct::<DestinyActivityHistoryResults>(
+            "Destiny.HistoricalStats.DestinyActivityHistoryResults",
+        );
+        detector.check_struct::<DestinyHistoricalStatsAccountResult>(
+            "Destiny.HistoricalStats.DestinyHistoricalStatsAccountResult",
+        );
+        detector.check_struct::<DestinyAggregateActivityResults>(
+            "Destiny.HistoricalStats.DestinyAggregateActivityResults",
+        );
+        detector.check_struct::<DestinyHistoricalWeaponStatsData>(
+            "Destiny.HistoricalStats.DestinyHistoricalWeaponStatsData",
+        );
+        detector.check_struct::<DestinyClanAggregateStat>(
+            "Destiny.HistoricalStats.DestinyClanAggregateStat",
+        );
+        detector.check_struct::<DestinyLeaderboard>("Destiny.HistoricalStats.DestinyLeaderboard");
+        detector.check_struct::<DestinyHistoricalStatsDefinition>(
+            "Destiny.HistoricalStats.Definitions.DestinyHistoricalStatsDefinition",
+        );
+        detector.check_struct::<DestinyMilestone>("Destiny.Milestones.DestinyMilestone");
+        detector
+            .check_struct::<DestinyPublicMilestone>("Destiny.Milestones.DestinyPublicMilestone");
+        detector
+            .check_struct::<DestinyMilestoneContent>("Destiny.Milestones.DestinyMilestoneContent");
+        detector.check_struct::<DestinyInventoryItemDefinition>(
+            "Destiny.Definitions.DestinyInventoryItemDefinition",
+        );
+        detector.check_struct::<DestinySocketTypeDefinition>(
+            "Destiny.Definitions.Sockets.DestinySocketTypeDefinition",
+        );
+        detector.check_struct::<DestinySocketCategoryDefinition>(
+            "Destiny.Definitions.Sockets.DestinySocketCategoryDefinition",
+        );
+        detector.check_struct::<DestinyPlugSetDefinition>(
+            "Destiny.Definitions.Sockets.DestinyPlugSetDefinition",
+        );
+        detector.check_struct::<DestinyActivityDefinition>(
+            "Destiny.Definitions.DestinyActivityDefinition",
+        );
+        detector.check_struct::<DestinyActivityModifierDefinition>(
+            "Destiny.Definitions.ActivityModifiers.DestinyActivityModifierDefinition",
+        );
+        detector
+            .check_struct::<DestinyVendorDefinition>("Destiny.Definitions.DestinyVendorDefinition");
+        detector.check_struct::<DestinyMilestoneDefinition>(
+            "Destiny.Definitions.Milestones.DestinyMilestoneDefinition",
+        );
+        detector.check_struct::<DestinyRecordDefinition>(
+            "Destiny.Definitions.Records.DestinyRecordDefinition",
+        );
+        detector.check_struct::<DestinyPresentationNodeDefinition>(
+            "Destiny.Definitions.Presentation.DestinyPresentationNodeDefinition",
+        );
+
+        detector.check_enum::<BungieMembershipType>("BungieMembershipType");
+        detector.check_enum::<DestinyComponentType>("Destiny.DestinyComponentType");
+        detector.check_enum::<DestinyActivityModeType>(
+            "Destiny.HistoricalStats.Definitions.DestinyActivityModeType",
+        );
+        detector.check_enum::<DestinyProgressionScope>("Destiny.DestinyProgressionScope");
+        detector.check_enum::<DestinyProgressionStepDisplayEffect>(
+            "Destiny.DestinyProgressionStepDisplayEffect",
+        );
+        detector.check_enum::<SocketTypeActionType>("Destiny.SocketTypeActionType");
+        detector.check_enum::<DestinySocketVisibility>("Destiny.DestinySocketVisibility");
+        detector.check_enum::<DestinySocketCategoryStyle>("Destiny.DestinySocketCategoryStyle");
+        detector.check_enum::<TierType>("Destiny.TierType");
+        detector.check_enum::<BucketScope>("Destiny.BucketScope");
+        detector.check_enum::<BucketCategory>("Destiny.BucketCategory");
+        detector.check_enum::<ItemLocation>("Destiny.ItemLocation");
+        detector.check_enum::<DestinyStatAggregationType>("Destiny.DestinyStatAggregationType");
+        detector.check_enum::<DestinyStatCategory>("Destiny.DestinyStatCategory");
+        detector.check_enum::<DestinyAmmunitionType>("Destiny.DestinyAmmunitionType");
+        detector.check_enum::<DestinyClass>("Destiny.DestinyClass");
+        detector.check_enum::<DestinyGender>("Destiny.DestinyGender");
+        detector
+            .check_enum::<DestinyVendorItemRefundPolicy>("Destiny.DestinyVendorItemRefundPolicy");
+        detector.check_enum::<DamageType>("Destiny.DamageType");
+        detector.check_enum::<DestinyItemSubType>("Destiny.DestinyItemSubType");
+        detector.check_enum::<PlugAvailabilityMode>("Destiny.PlugAvailabilityMode");
+        detector.check_enum::<DestinyEnergyType>("Destiny.DestinyEnergyType");
+        detector.check_enum::<ItemPerkVisibility>("Destiny.ItemPerkVisibility");
+        detector.check_enum::<SpecialItemType>("Destiny.SpecialItemType");
+        detector.check_enum::<DestinyItemType>("Destiny.DestinyItemType");
+        detector.check_enum::<DestinyBreakerType>("Destiny.DestinyBreakerType");
+        detector.check_enum::<DestinyProgressionRewardItemAcquisitionBehavior>(
+            "Destiny.DestinyProgressionRewardItemAcquisitionBehavior",
+        );
+        detector.check_enum::<ItemBindStatus>("Destiny.ItemBindStatus");
+        detector.check_enum::<DestinyPresentationNodeType>("Destiny.DestinyPresentationNodeType");
+        detector.check_enum::<DestinyScope>("Destiny.DestinyScope");
+        detector.check_enum::<DestinyPresentationDisplayStyle>(
+            "Destiny.DestinyPresentationDisplayStyle",
+        );
+        detector
+            .check_enum::<DestinyPresentationScreenStyle>("Destiny.DestinyPresentationScreenStyle");
+        detector.check_enum::<DestinyRecordValueStyle>("Destiny.DestinyRecordValueStyle");
+        detector.check_enum::<DestinyRecordToastStyle>("Destiny.DestinyRecordToastStyle");
+        detector.check_enum::<DestinyGamePrivacySetting>("Destiny.DestinyGamePrivacySetting");
+        detector.check_enum::<DestinyRace>("Destiny.DestinyRace");
+        detector
+            .check_enum::<DestinyActivityDifficultyTier>("Destiny.DestinyActivityDifficultyTier");
+        detector.check_enum::<DestinyTalentNodeState>("Destiny.DestinyTalentNodeState");
+        detector.check_enum::<DestinyStatsGroupType>(
+            "Destiny.HistoricalStats.Definitions.DestinyStatsGroupType",
+        );
+        detector.check_enum::<PeriodType>("Destiny.HistoricalStats.Definitions.PeriodType");
+        detector.check_enum::<DestinyStatsCategoryType>(
+            "Destiny.HistoricalStats.Definitions.DestinyStatsCategoryType",
+        );
+        detector.check_enum::<UnitType>("Destiny.HistoricalStats.Definitions.UnitType");
+        detector.check_enum::<DestinyStatsMergeMethods>(
+            "Destiny.HistoricalStats.Definitions.DestinyStatsMergeMethods",
+        );
+        detector.check_enum::<ComponentPrivacySetting>("Components.ComponentPrivacySetting");
+
+        let drift = detector.finish();
+        for drift in &drift {
+            println!("{drift}");
+        }
+        assert!(
+            drift.is_empty(),
+            "{} differences from {}",
+            drift.len(),
+            path.display()
+        );
+    }
+}
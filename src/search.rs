@@ -0,0 +1,516 @@
+//! DIM-style item search, such as `is:weapon tier:exotic perk:"Rampage" stat:recovery>=20`.
+//!
+//! Terms separated by spaces must all match. `or` joins alternatives, parentheses group, and a
+//! leading `-` or `not` negates. A bare word matches item names.
+
+use std::fmt;
+use std::iter::Peekable;
+use std::str::FromStr;
+
+use crate::DestinyInventoryItemManifest;
+use crate::inventory::{Inventory, ItemOwner, OwnedItem};
+use crate::types::TierType;
+use crate::types::destiny::{DamageType, DestinyClass, DestinyItemType, ItemState};
+use crate::types::ids::ItemInstanceId;
+
+/// Stat names accepted by `stat:`. Any other stat can be given by hash.
+const STATS: [(&str, u32); 20] = [
+    ("mobility", 2996146975),
+    ("resilience", 392767087),
+    ("recovery", 1943323491),
+    ("discipline", 1735777505),
+    ("intellect", 144602215),
+    ("strength", 4244567218),
+    ("impact", 4043523819),
+    ("range", 1240592695),
+    ("stability", 155624089),
+    ("handling", 943549884),
+    ("reload", 4188031367),
+    ("rpm", 4284893193),
+    ("magazine", 3871231066),
+    ("velocity", 2523465841),
+    ("blastradius", 3614673599),
+    ("chargetime", 2961396640),
+    ("drawtime", 447667954),
+    ("aimassistance", 1345609583),
+    ("zoom", 3555269338),
+    ("recoil", 2715839340),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    expr: Expr,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    And(Vec<Expr>),
+    Or(Vec<Expr>),
+    Not(Box<Expr>),
+    Filter(Filter),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Filter {
+    Is(Is),
+    Tier(TierType),
+    Name(String),
+    Perk(String),
+    Stat(u32, Comparison),
+    Power(Comparison),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Is {
+    ItemType(DestinyItemType),
+    Tier(TierType),
+    Damage(DamageType),
+    Class(DestinyClass),
+    State(ItemState),
+    Equipped,
+    Vault,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Comparison {
+    op: Op,
+    value: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Eq,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The query ended where a term was expected.
+    UnexpectedEnd,
+    /// A closing parenthesis without an opening one, or the reverse.
+    UnbalancedParentheses,
+    UnknownFilter(String),
+    UnknownValue {
+        filter: String,
+        value: String,
+    },
+    /// A `stat:` or `power:` value that is not a comparison such as `>=20`.
+    InvalidComparison(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedEnd => f.write_str("query ended unexpectedly"),
+            ParseError::UnbalancedParentheses => f.write_str("unbalanced parentheses"),
+            ParseError::UnknownFilter(filter) => write!(f, "unknown filter `{filter}`"),
+            ParseError::UnknownValue { filter, value } => {
+                write!(f, "unknown value `{value}` for `{filter}`")
+            }
+            ParseError::InvalidComparison(value) => write!(f, "invalid comparison `{value}`"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl FromStr for Query {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Query, ParseError> {
+        let mut tokens = tokenize(s).into_iter().peekable();
+        let expr = if tokens.peek().is_none() {
+            Expr::And(Vec::new())
+        } else {
+            parse_or(&mut tokens)?
+        };
+
+        match tokens.next() {
+            None => Ok(Query { expr }),
+            Some(_) => Err(ParseError::UnbalancedParentheses),
+        }
+    }
+}
+
+impl Query {
+    /// `item_definitions` resolves the names of plugged perks.
+    pub fn matches(
+        &self,
+        item: &OwnedItem,
+        item_definitions: &DestinyInventoryItemManifest,
+    ) -> bool {
+        self.expr.matches(item, item_definitions)
+    }
+}
+
+impl Inventory<'_> {
+    /// The instance IDs of matching items. Uninstanced items are never returned.
+    pub fn search(&self, query: &Query) -> Vec<ItemInstanceId> {
+        self.items
+            .iter()
+            .filter(|item| query.matches(item, self.item_definitions))
+            .filter_map(|item| item.item.item_instance_id)
+            .collect()
+    }
+}
+
+impl Expr {
+    fn matches(&self, item: &OwnedItem, item_definitions: &DestinyInventoryItemManifest) -> bool {
+        match self {
+            Expr::And(exprs) => exprs.iter().all(|e| e.matches(item, item_definitions)),
+            Expr::Or(exprs) => exprs.iter().any(|e| e.matches(item, item_definitions)),
+            Expr::Not(expr) => !expr.matches(item, item_definitions),
+            Expr::Filter(filter) => filter.matches(item, item_definitions),
+        }
+    }
+}
+
+impl Filter {
+    fn matches(&self, item: &OwnedItem, item_definitions: &DestinyInventoryItemManifest) -> bool {
+        match self {
+            Filter::Is(is) => is.matches(item),
+            Filter::Tier(tier) => item
+                .definition
+                .is_some_and(|d| d.inventory.tier_type == *tier),
+            Filter::Name(name) => item
+                .definition
+                .is_some_and(|d| contains(&d.display_properties.name, name)),
+            Filter::Perk(name) => item.sockets.is_some_and(|sockets| {
+                sockets.sockets.iter().any(|socket| {
+                    item_definitions
+                        .get(&socket.plug_hash.to_string())
+                        .is_some_and(|plug| contains(&plug.display_properties.name, name))
+                })
+            }),
+            Filter::Stat(stat_hash, comparison) => item
+                .stats
+                .and_then(|stats| stats.stats.get(stat_hash))
+                .is_some_and(|stat| comparison.matches(stat.value)),
+            Filter::Power(comparison) => item
+                .instance
+                .is_some_and(|instance| comparison.matches(instance.primary_stat.value)),
+        }
+    }
+}
+
+impl Is {
+    fn matches(&self, item: &OwnedItem) -> bool {
+        let definition = item.definition;
+        match *self {
+            Is::ItemType(item_type) => definition.is_some_and(|d| d.item_type == item_type),
+            Is::Tier(tier) => definition.is_some_and(|d| d.inventory.tier_type == tier),
+            Is::Damage(damage_type) => match item.instance {
+                Some(instance) => instance.damage_type == damage_type,
+                None => definition.is_some_and(|d| d.default_damage_type == damage_type),
+            },
+            Is::Class(class) => definition.is_some_and(|d| d.class_type == class),
+            Is::State(state) => item.item.state.contains(state),
+            Is::Equipped => item.equipped,
+            Is::Vault => item.owner == ItemOwner::Vault,
+        }
+    }
+}
+
+impl Comparison {
+    fn matches(&self, value: i32) -> bool {
+        match self.op {
+            Op::Eq => value == self.value,
+            Op::Lt => value < self.value,
+            Op::Le => value <= self.value,
+            Op::Gt => value > self.value,
+            Op::Ge => value >= self.value,
+        }
+    }
+}
+
+impl FromStr for Comparison {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Comparison, ParseError> {
+        let (op, value) = [
+            (">=", Op::Ge),
+            ("<=", Op::Le),
+            (">", Op::Gt),
+            ("<", Op::Lt),
+            ("=", Op::Eq),
+        ]
+        .into_iter()
+        .find_map(|(prefix, op)| s.strip_prefix(prefix).map(|rest| (op, rest)))
+        .unwrap_or((Op::Eq, s));
+
+        value
+            .parse()
+            .map(|value| Comparison { op, value })
+            .map_err(|_| ParseError::InvalidComparison(s.to_owned()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    Not,
+    Or,
+    And,
+    Term(String),
+}
+
+fn tokenize(s: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = s.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::Open);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::Close);
+            }
+            '-' => {
+                chars.next();
+                tokens.push(Token::Not);
+            }
+            _ => {
+                let mut term = String::new();
+                let mut quoted = false;
+                while let Some(&c) = chars.peek() {
+                    if !quoted && (c.is_whitespace() || c == '(' || c == ')') {
+                        break;
+                    }
+                    chars.next();
+                    if c == '"' {
+                        quoted = !quoted;
+                    } else {
+                        term.push(c);
+                    }
+                }
+
+                tokens.push(match term.to_lowercase().as_str() {
+                    "or" => Token::Or,
+                    "and" => Token::And,
+                    "not" => Token::Not,
+                    _ => Token::Term(term),
+                });
+            }
+        }
+    }
+
+    tokens
+}
+
+type Tokens = Peekable<std::vec::IntoIter<Token>>;
+
+fn parse_or(tokens: &mut Tokens) -> Result<Expr, ParseError> {
+    let mut alternatives = vec![parse_and(tokens)?];
+    while tokens.next_if_eq(&Token::Or).is_some() {
+        alternatives.push(parse_and(tokens)?);
+    }
+
+    Ok(match alternatives.len() {
+        1 => alternatives.remove(0),
+        _ => Expr::Or(alternatives),
+    })
+}
+
+fn parse_and(tokens: &mut Tokens) -> Result<Expr, ParseError> {
+    let mut terms = vec![parse_not(tokens)?];
+    loop {
+        match tokens.peek() {
+            None | Some(Token::Or) | Some(Token::Close) => break,
+            Some(Token::And) => {
+                tokens.next();
+            }
+            Some(_) => {}
+        }
+        terms.push(parse_not(tokens)?);
+    }
+
+    Ok(match terms.len() {
+        1 => terms.remove(0),
+        _ => Expr::And(terms),
+    })
+}
+
+fn parse_not(tokens: &mut Tokens) -> Result<Expr, ParseError> {
+    match tokens.next() {
+        None => Err(ParseError::UnexpectedEnd),
+        Some(Token::Not) => Ok(Expr::Not(Box::new(parse_not(tokens)?))),
+        Some(Token::Open) => {
+            let expr = parse_or(tokens)?;
+            match tokens.next() {
+                Some(Token::Close) => Ok(expr),
+                _ => Err(ParseError::UnbalancedParentheses),
+            }
+        }
+        Some(Token::Close) => Err(ParseError::UnbalancedParentheses),
+        Some(Token::Or) | Some(Token::And) => Err(ParseError::UnexpectedEnd),
+        Some(Token::Term(term)) => parse_filter(&term).map(Expr::Filter),
+    }
+}
+
+fn parse_filter(term: &str) -> Result<Filter, ParseError> {
+    let Some((filter, value)) = term.split_once(':') else {
+        return Ok(Filter::Name(term.to_owned()));
+    };
+    let unknown = || ParseError::UnknownValue {
+        filter: filter.to_owned(),
+        value: value.to_owned(),
+    };
+
+    match filter.to_lowercase().as_str() {
+        "is" => parse_is(&value.to_lowercase())
+            .map(Filter::Is)
+            .ok_or_else(unknown),
+        "tier" => parse_tier(&value.to_lowercase())
+            .map(Filter::Tier)
+            .ok_or_else(unknown),
+        "name" => Ok(Filter::Name(value.to_owned())),
+        "perk" => Ok(Filter::Perk(value.to_owned())),
+        "power" => value.parse().map(Filter::Power),
+        "stat" => {
+            let split = value
+                .find([':', '<', '>', '='])
+                .ok_or_else(|| ParseError::InvalidComparison(value.to_owned()))?;
+            let (name, comparison) = value.split_at(split);
+            let comparison = comparison.strip_prefix(':').unwrap_or(comparison);
+
+            let name = name.to_lowercase();
+            let stat_hash = STATS
+                .iter()
+                .find(|(stat, _)| *stat == name)
+                .map(|(_, hash)| *hash)
+                .or_else(|| name.parse().ok())
+                .ok_or_else(unknown)?;
+
+            Ok(Filter::Stat(stat_hash, comparison.parse()?))
+        }
+        _ => Err(ParseError::UnknownFilter(filter.to_owned())),
+    }
+}
+
+fn parse_is(value: &str) -> Option<Is> {
+    Some(match value {
+        "weapon" => Is::ItemType(DestinyItemType::Weapon),
+        "armor" => Is::ItemType(DestinyItemType::Armor),
+        "ghost" => Is::ItemType(DestinyItemType::Ghost),
+        "kinetic" => Is::Damage(DamageType::Kinetic),
+        "arc" => Is::Damage(DamageType::Arc),
+        "solar" => Is::Damage(DamageType::Thermal),
+        "void" => Is::Damage(DamageType::Void),
+        "stasis" => Is::Damage(DamageType::Stasis),
+        "strand" => Is::Damage(DamageType::Strand),
+        "titan" => Is::Class(DestinyClass::Titan),
+        "hunter" => Is::Class(DestinyClass::Hunter),
+        "warlock" => Is::Class(DestinyClass::Warlock),
+        "locked" => Is::State(ItemState::Locked),
+        "tracked" => Is::State(ItemState::Tracked),
+        "masterwork" | "masterworked" => Is::State(ItemState::Masterwork),
+        "crafted" => Is::State(ItemState::Crafted),
+        "equipped" => Is::Equipped,
+        "invault" | "vault" => Is::Vault,
+        value => Is::Tier(parse_tier(value)?),
+    })
+}
+
+/// Tiers by their in-game names rather than Bungie's enum names.
+fn parse_tier(value: &str) -> Option<TierType> {
+    Some(match value {
+        "exotic" => TierType::Exotic,
+        "legendary" => TierType::Superior,
+        "rare" => TierType::Rare,
+        "uncommon" => TierType::Common,
+        "common" => TierType::Basic,
+        _ => return None,
+    })
+}
+
+fn contains(haystack: &str, needle: &str) -> bool {
+    haystack.to_lowercase().contains(&needle.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseError, Query};
+    use crate::DestinyInventoryItemManifest;
+    use crate::inventory::Inventory;
+    use crate::test_fixtures::{definitions, profile};
+    use crate::types::destiny::responses::DestinyProfileResponse;
+    use crate::types::ids::ItemInstanceId;
+
+    const WEAPON: ItemInstanceId = ItemInstanceId(6917529800000000001);
+    const HELMET: ItemInstanceId = ItemInstanceId(6917529800000000002);
+    const VAULTED: ItemInstanceId = ItemInstanceId(6917529800000000003);
+
+    fn fixtures() -> (DestinyProfileResponse, DestinyInventoryItemManifest) {
+        let mut definitions: DestinyInventoryItemManifest =
+            definitions("DestinyInventoryItemDefinition");
+
+        let mut rampage = definitions["1363886209"].clone();
+        rampage.display_properties.name = String::from("Rampage");
+        definitions.insert(String::from("3038247973"), rampage);
+
+        (profile("profile.json"), definitions)
+    }
+
+    fn search(query: &str) -> Vec<ItemInstanceId> {
+        let (profile, definitions) = fixtures();
+        let inventory = Inventory::new(&profile, &definitions);
+        inventory.search(&query.parse().unwrap())
+    }
+
+    #[test]
+    fn filters() {
+        assert_eq!(search("is:weapon"), [WEAPON, VAULTED]);
+        assert_eq!(search("is:armor"), [HELMET]);
+        assert_eq!(search("tier:exotic"), [WEAPON]);
+        assert_eq!(search("is:legendary"), [HELMET, VAULTED]);
+        assert_eq!(search(r#"perk:"rampage""#), [VAULTED]);
+        assert_eq!(search("stat:recovery>=10"), [HELMET]);
+        assert_eq!(search("stat:recovery:>10"), Vec::<ItemInstanceId>::new());
+        assert_eq!(search("power:>=2000"), [WEAPON, HELMET]);
+        assert_eq!(search("is:equipped"), [WEAPON]);
+        assert_eq!(search("is:locked"), [WEAPON]);
+        assert_eq!(search("is:masterwork"), [VAULTED]);
+        assert_eq!(search("hand cannon"), [VAULTED]);
+        assert_eq!(search(r#"name:"hand cannon""#), [VAULTED]);
+    }
+
+    #[test]
+    fn combinators() {
+        assert_eq!(search("is:weapon -is:locked"), [VAULTED]);
+        assert_eq!(search("is:weapon and tier:exotic"), [WEAPON]);
+        assert_eq!(search("is:armor or tier:exotic"), [WEAPON, HELMET]);
+        assert_eq!(search("-(is:armor or tier:exotic)"), [VAULTED]);
+        assert_eq!(search("not is:weapon"), [HELMET]);
+        assert_eq!(search(""), [WEAPON, HELMET, VAULTED]);
+    }
+
+    #[test]
+    fn errors() {
+        let parse = |s: &str| s.parse::<Query>().unwrap_err();
+        assert_eq!(
+            parse("is:sparrow").to_string(),
+            "unknown value `sparrow` for `is`"
+        );
+        assert_eq!(
+            parse("foo:bar"),
+            ParseError::UnknownFilter(String::from("foo"))
+        );
+        assert_eq!(parse("(is:weapon"), ParseError::UnbalancedParentheses);
+        assert_eq!(parse("is:weapon)"), ParseError::UnbalancedParentheses);
+        assert_eq!(parse("is:weapon or"), ParseError::UnexpectedEnd);
+        assert_eq!(
+            parse("power:>high"),
+            ParseError::InvalidComparison(String::from(">high"))
+        );
+    }
+}
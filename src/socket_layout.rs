@@ -0,0 +1,332 @@
+use crate::inventory::OwnedItem;
+use crate::types::definitions::DestinyInventoryItemDefinition;
+use crate::types::destiny::DestinySocketCategoryStyle;
+use crate::types::destiny::definitions::sockets::{
+    DestinyPlugWhitelistEntryDefinition, DestinySocketCategoryDefinition,
+    DestinySocketTypeDefinition,
+};
+use crate::{
+    DestinyInventoryItemManifest, DestinyPlugSetManifest, DestinySocketCategoryManifest,
+    DestinySocketTypeManifest,
+};
+
+/// An item's sockets grouped into categories, ordered by [`SocketGroup`] and then as the item
+/// definition lists them.
+#[derive(Debug)]
+pub struct SocketLayout<'a> {
+    pub categories: Vec<SocketCategory<'a>>,
+}
+
+#[derive(Debug)]
+pub struct SocketCategory<'a> {
+    pub category_hash: u32,
+    pub definition: Option<&'a DestinySocketCategoryDefinition>,
+    pub group: SocketGroup,
+    pub sockets: Vec<Socket<'a>>,
+}
+
+/// Where a socket category belongs in an item's detail view, in display order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SocketGroup {
+    /// The item's intrinsic trait, such as a weapon frame or artifice armor.
+    Intrinsic,
+    /// Weapon perks and other freely switched traits.
+    Perks,
+    /// Mods and masterworks.
+    Mods,
+    /// Shaders, ornaments and other appearance options.
+    Cosmetics,
+    /// Categories that fit none of the above, such as subclass abilities.
+    Other,
+}
+
+#[derive(Debug)]
+pub struct Socket<'a> {
+    /// The socket's position in the item definition and the sockets component.
+    pub index: usize,
+    pub socket_type: Option<&'a DestinySocketTypeDefinition>,
+    /// The plugged item, or the definition's initial plug when the sockets component was not
+    /// requested. `None` for an empty socket.
+    pub plug_hash: Option<u32>,
+    pub plug: Option<&'a DestinyInventoryItemDefinition>,
+    pub is_enabled: bool,
+    pub is_visible: bool,
+    /// Plugs that can be switched to freely: the definition's fixed options and reusable plug
+    /// set, and the item's reusable plugs component.
+    pub reusable_plugs: Vec<u32>,
+    /// Plugs that can currently roll in this socket, from the randomized plug set.
+    pub randomized_plugs: Vec<u32>,
+    /// The plug categories the socket accepts.
+    pub whitelist: &'a [DestinyPlugWhitelistEntryDefinition],
+}
+
+impl<'a> SocketLayout<'a> {
+    /// Returns `None` when the item has no definition or its definition has no socket block.
+    /// Sockets outside every category are left out.
+    pub fn new(
+        item: &OwnedItem<'a>,
+        item_definitions: &'a DestinyInventoryItemManifest,
+        socket_type_definitions: &'a DestinySocketTypeManifest,
+        socket_category_definitions: &'a DestinySocketCategoryManifest,
+        plug_set_definitions: &'a DestinyPlugSetManifest,
+    ) -> Option<Self> {
+        let block = item.definition?.sockets.as_ref()?;
+
+        let mut categories = block
+            .socket_categories
+            .iter()
+            .map(|category| {
+                let definition =
+                    socket_category_definitions.get(&category.socket_category_hash.to_string());
+                let sockets = category
+                    .socket_indexes
+                    .iter()
+                    .filter_map(|&index| {
+                        socket(
+                            item,
+                            usize::try_from(index).ok()?,
+                            item_definitions,
+                            socket_type_definitions,
+                            plug_set_definitions,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                SocketCategory {
+                    category_hash: category.socket_category_hash,
+                    definition,
+                    group: group(definition, &sockets),
+                    sockets,
+                }
+            })
+            .collect::<Vec<_>>();
+        categories.sort_by_key(|c| c.group);
+
+        Some(SocketLayout { categories })
+    }
+
+    /// The categories in `group`, in definition order.
+    pub fn group(&self, group: SocketGroup) -> impl Iterator<Item = &SocketCategory<'a>> {
+        self.categories.iter().filter(move |c| c.group == group)
+    }
+
+    /// The first category with the given style, e.g. `Intrinsic` or `Consumable` for mods.
+    pub fn by_style(&self, style: DestinySocketCategoryStyle) -> Option<&SocketCategory<'a>> {
+        self.categories
+            .iter()
+            .find(|c| c.definition.is_some_and(|d| d.category_style == style))
+    }
+}
+
+/// Groups a category by its style, falling back to the plug categories its sockets accept for
+/// styles that several groups share. Reusable sockets hold perks, but also shaders and
+/// ornaments.
+fn group(definition: Option<&DestinySocketCategoryDefinition>, sockets: &[Socket]) -> SocketGroup {
+    let accepts = |matches: fn(&str) -> bool| {
+        sockets
+            .iter()
+            .flat_map(|s| s.whitelist)
+            .any(|w| matches(&w.category_identifier))
+    };
+    let by_plugs = || {
+        if accepts(is_cosmetic) {
+            Some(SocketGroup::Cosmetics)
+        } else if accepts(|id| id.starts_with("intrinsics")) {
+            Some(SocketGroup::Intrinsic)
+        } else if accepts(is_mod) {
+            Some(SocketGroup::Mods)
+        } else {
+            None
+        }
+    };
+
+    match definition.map(|d| d.category_style) {
+        Some(DestinySocketCategoryStyle::Intrinsic) => SocketGroup::Intrinsic,
+        Some(DestinySocketCategoryStyle::Consumable | DestinySocketCategoryStyle::EnergyMeter) => {
+            SocketGroup::Mods
+        }
+        Some(
+            DestinySocketCategoryStyle::Reusable
+            | DestinySocketCategoryStyle::LargePerk
+            | DestinySocketCategoryStyle::Unlockable,
+        ) => by_plugs().unwrap_or(SocketGroup::Perks),
+        _ => by_plugs().unwrap_or(SocketGroup::Other),
+    }
+}
+
+fn is_cosmetic(identifier: &str) -> bool {
+    ["shader", "skins", "ornament", "hologram", "memento"]
+        .iter()
+        .any(|part| identifier.contains(part))
+}
+
+fn is_mod(identifier: &str) -> bool {
+    identifier.starts_with("enhancements")
+        || identifier.contains("masterworks")
+        || identifier.contains(".mod_")
+}
+
+fn socket<'a>(
+    item: &OwnedItem<'a>,
+    index: usize,
+    item_definitions: &'a DestinyInventoryItemManifest,
+    socket_type_definitions: &'a DestinySocketTypeManifest,
+    plug_set_definitions: &'a DestinyPlugSetManifest,
+) -> Option<Socket<'a>> {
+    let entry = item
+        .definition?
+        .sockets
+        .as_ref()?
+        .socket_entries
+        .get(index)?;
+    let state = item.sockets.and_then(|s| s.sockets.get(index));
+    let socket_type = socket_type_definitions.get(&entry.socket_type_hash.to_string());
+    let plug_set = |hash: Option<u32>| {
+        hash.and_then(|hash| plug_set_definitions.get(&hash.to_string()))
+            .into_iter()
+            .flat_map(|set| &set.reusable_plug_items)
+    };
+
+    let plug_hash = state.map_or(entry.single_initial_item_hash, |s| s.plug_hash);
+    let plug_hash = (plug_hash != 0).then_some(plug_hash);
+
+    let mut reusable_plugs = Vec::new();
+    let fixed = entry.reusable_plug_items.iter().map(|p| p.plug_item_hash);
+    let from_set = plug_set(entry.reusable_plug_set_hash).map(|p| p.plug_item_hash);
+    let from_component = item
+        .reusable_plugs
+        .and_then(|r| r.plugs.get(&i32::try_from(index).ok()?))
+        .into_iter()
+        .flatten()
+        .map(|p| p.plug_item_hash);
+    for hash in fixed.chain(from_set).chain(from_component) {
+        if !reusable_plugs.contains(&hash) {
+            reusable_plugs.push(hash);
+        }
+    }
+
+    Some(Socket {
+        index,
+        socket_type,
+        plug_hash,
+        plug: plug_hash.and_then(|hash| item_definitions.get(&hash.to_string())),
+        is_enabled: state.is_none_or(|s| s.is_enabled),
+        is_visible: state.map_or(entry.default_visible, |s| s.is_visible),
+        reusable_plugs,
+        randomized_plugs: plug_set(entry.randomized_plug_set_hash)
+            .filter(|p| p.currently_can_roll)
+            .map(|p| p.plug_item_hash)
+            .collect(),
+        whitelist: socket_type.map_or(&[], |t| &t.plug_whitelist),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SocketGroup, SocketLayout, group};
+    use crate::inventory::Inventory;
+    use crate::test_fixtures::{definitions, profile};
+    use crate::types::destiny::DestinySocketCategoryStyle;
+    use crate::types::ids::ItemInstanceId;
+    use crate::{
+        DestinyInventoryItemManifest, DestinyPlugSetManifest, DestinySocketCategoryManifest,
+        DestinySocketTypeManifest,
+    };
+
+    #[test]
+    fn resolves() {
+        let weapons = profile("profile.json");
+        let items: DestinyInventoryItemManifest = definitions("DestinyInventoryItemDefinition");
+        let socket_types: DestinySocketTypeManifest = definitions("DestinySocketTypeDefinition");
+        let socket_categories: DestinySocketCategoryManifest =
+            definitions("DestinySocketCategoryDefinition");
+        let plug_sets: DestinyPlugSetManifest = definitions("DestinyPlugSetDefinition");
+        let inventory = Inventory::new(&weapons, &items);
+        let layout = |id| {
+            let item = inventory.get(ItemInstanceId(id)).unwrap();
+            SocketLayout::new(item, &items, &socket_types, &socket_categories, &plug_sets).unwrap()
+        };
+
+        let weapon = layout(6917529800000000003);
+        assert_eq!(weapon.categories.len(), 1);
+        assert_eq!(weapon.categories[0].group, SocketGroup::Perks);
+        let perks = weapon
+            .by_style(DestinySocketCategoryStyle::Reusable)
+            .unwrap();
+        assert_eq!(perks.sockets.len(), 2);
+        let frame = &perks.sockets[0];
+        assert_eq!(frame.plug_hash, Some(2420895100));
+        assert_eq!(frame.plug.unwrap().display_properties.name, "Outlaw");
+        assert_eq!(frame.reusable_plugs, [2420895100, 2779035018]);
+        assert_eq!(frame.randomized_plugs, [2420895100, 2779035018]);
+        assert_eq!(frame.whitelist[0].category_identifier, "frames");
+        assert_eq!(perks.sockets[1].randomized_plugs, [3038247973, 1015611457]);
+
+        let armor = profile("profile_armor.json");
+        let inventory = Inventory::new(&armor, &items);
+        let chest = inventory.get(ItemInstanceId(6917529800000000101)).unwrap();
+        let chest = SocketLayout::new(chest, &items, &socket_types, &socket_categories, &plug_sets)
+            .unwrap();
+        // The definition lists cosmetics before the intrinsic trait.
+        let groups = chest
+            .categories
+            .iter()
+            .map(|c| {
+                (
+                    c.definition.unwrap().display_properties.name.as_str(),
+                    c.group,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            groups,
+            [
+                ("Intrinsic Traits", SocketGroup::Intrinsic),
+                ("Armor Mods", SocketGroup::Mods),
+                ("Armor Tier", SocketGroup::Mods),
+                ("Armor Cosmetics", SocketGroup::Cosmetics),
+            ]
+        );
+        assert_eq!(chest.group(SocketGroup::Mods).count(), 2);
+        let intrinsic = &chest.categories[0].sockets[0];
+        assert_eq!(
+            intrinsic.plug.unwrap().display_properties.name,
+            "Artifice Armor"
+        );
+        let shader = &chest.categories[3].sockets[0];
+        assert_eq!(
+            shader.plug.unwrap().display_properties.name,
+            "Default Shader"
+        );
+
+        // Without a category definition the accepted plugs decide.
+        assert_eq!(
+            group(None, &chest.categories[3].sockets),
+            SocketGroup::Cosmetics
+        );
+        assert_eq!(
+            group(None, &chest.categories[0].sockets),
+            SocketGroup::Intrinsic
+        );
+        assert_eq!(group(None, &chest.categories[1].sockets), SocketGroup::Mods);
+        assert_eq!(
+            group(None, &weapon.categories[0].sockets),
+            SocketGroup::Other
+        );
+        let mods = chest
+            .by_style(DestinySocketCategoryStyle::Consumable)
+            .unwrap();
+        assert_eq!(mods.sockets[0].index, 2);
+        assert_eq!(mods.sockets[0].plug_hash, Some(1703647492));
+        assert_eq!(
+            mods.sockets[0].reusable_plugs,
+            [4204488676, 1703647492, 2263321587]
+        );
+        assert_eq!(mods.sockets[1].plug_hash, Some(2000000012));
+        assert_eq!(mods.sockets[1].reusable_plugs, [2000000012]);
+        assert_eq!(
+            chest.categories[2].sockets[0].socket_type.unwrap().hash,
+            2655746324
+        );
+    }
+}
@@ -0,0 +1,107 @@
+//! Loaders for the fixture corpus in `tests/fixtures`, shared by the unit tests, and helpers
+//! that add items to the fixture profile before it is parsed.
+
+use std::fs;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+use crate::types::destiny::responses::DestinyProfileResponse;
+use crate::types::response::BungieResponse;
+
+/// Parses `tests/fixtures/{name}` as `T`.
+pub(crate) fn fixture<T: DeserializeOwned>(name: &str) -> T {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name);
+    let text = fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+    serde_json::from_str(&text).unwrap_or_else(|e| panic!("{}: {e}", path.display()))
+}
+
+/// Parses a definition table, e.g. `definitions("DestinyInventoryItemDefinition")`.
+pub(crate) fn definitions<T: DeserializeOwned>(name: &str) -> T {
+    fixture(&format!("definitions/{name}.json"))
+}
+
+/// Parses a profile fixture such as `profile.json` and unwraps its response.
+pub(crate) fn profile(name: &str) -> DestinyProfileResponse {
+    parse_profile(fixture(name))
+}
+
+/// Parses a profile fixture that was loaded as JSON with [`fixture`] and changed.
+pub(crate) fn parse_profile(profile: Value) -> DestinyProfileResponse {
+    serde_json::from_value::<BungieResponse<DestinyProfileResponse>>(profile)
+        .unwrap()
+        .response
+        .unwrap()
+}
+
+/// Adds a copy of the `source` instance under `id`, next to the source in its inventory, with
+/// copies of its instance, stats and sockets components. Returns the copied item.
+pub(crate) fn add_copy<'a>(profile: &'a mut Value, source: &str, id: &str) -> &'a mut Value {
+    let response = &mut profile["Response"];
+    for component in ["instances", "stats", "sockets"] {
+        let data = &mut response["itemComponents"][component]["data"];
+        if let Some(source) = data.get(source).cloned() {
+            data[id] = source;
+        }
+    }
+
+    let items = response
+        .as_object_mut()
+        .unwrap()
+        .iter_mut()
+        .flat_map(|(name, component)| match name.as_str() {
+            "profileInventory" => vec![&mut component["data"]],
+            "characterInventories" | "characterEquipment" => component["data"]
+                .as_object_mut()
+                .unwrap()
+                .values_mut()
+                .collect(),
+            _ => Vec::new(),
+        })
+        .filter_map(|inventory| inventory.get_mut("items").and_then(Value::as_array_mut))
+        .find(|items| items.iter().any(|i| i["itemInstanceId"] == source))
+        .unwrap_or_else(|| panic!("no item {source}"));
+    let mut copy = items
+        .iter()
+        .find(|i| i["itemInstanceId"] == source)
+        .unwrap()
+        .clone();
+    copy["itemInstanceId"] = id.into();
+    items.push(copy);
+    items.last_mut().unwrap()
+}
+
+/// The `id` entry of an item component such as `instances`.
+pub(crate) fn component<'a>(profile: &'a mut Value, component: &str, id: &str) -> &'a mut Value {
+    &mut profile["Response"]["itemComponents"][component]["data"][id]
+}
+
+/// Sets stat values in the item's stats component, leaving its other stats alone.
+pub(crate) fn set_stats(
+    profile: &mut Value,
+    id: &str,
+    stats: impl IntoIterator<Item = (u32, i32)>,
+) {
+    let component = &mut component(profile, "stats", id)["stats"];
+    for (stat_hash, value) in stats {
+        component[stat_hash.to_string()] = json!({ "statHash": stat_hash, "value": value });
+    }
+}
+
+/// Replaces the item's sockets with one enabled socket per plug.
+pub(crate) fn set_plugs(profile: &mut Value, id: &str, plugs: &[u32]) {
+    component(profile, "sockets", id)["sockets"] = plugs
+        .iter()
+        .map(|plug| {
+            json!({
+                "plugHash": plug,
+                "isEnabled": true,
+                "isVisible": true,
+                "enableFailIndexes": [],
+            })
+        })
+        .collect();
+}
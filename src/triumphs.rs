@@ -0,0 +1,285 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use crate::error::Result;
+use crate::types::destiny::DestinyRecordState;
+use crate::types::destiny::components::records::DestinyRecordComponent;
+use crate::types::destiny::quests::DestinyObjectiveProgress;
+use crate::types::destiny::responses::DestinyProfileResponse;
+use crate::types::ids::CharacterId;
+use crate::{
+    DestinyPresentationNodeDefinition, DestinyPresentationNodeManifest, DestinyRecordDefinition,
+    DestinyRecordManifest,
+};
+
+/// The triumph and seal presentation node trees, with each record's state and objectives.
+#[derive(Debug)]
+pub struct TriumphTree<'a> {
+    /// `None` when the root node has no definition.
+    pub seals: Option<TriumphNode<'a>>,
+    pub categories: Option<TriumphNode<'a>>,
+}
+
+#[derive(Debug)]
+pub struct TriumphNode<'a> {
+    pub node_hash: u32,
+    pub definition: &'a DestinyPresentationNodeDefinition,
+    /// Child nodes without a definition are left out.
+    pub children: Vec<TriumphNode<'a>>,
+    pub records: Vec<TriumphRecord<'a>>,
+    /// The record completed with the node, such as a seal's title.
+    pub completion_record: Option<TriumphRecord<'a>>,
+    /// Completed and total records in this node and every node below it, not counting
+    /// invisible ones.
+    pub completed: usize,
+    pub total: usize,
+}
+
+#[derive(Debug)]
+pub struct TriumphRecord<'a> {
+    pub record_hash: u32,
+    pub definition: Option<&'a DestinyRecordDefinition>,
+    /// `None` when neither the profile nor the character records component lists the record.
+    pub state: Option<DestinyRecordState>,
+    pub objectives: &'a [DestinyObjectiveProgress],
+}
+
+/// Seal totals for comparing players, e.g. on a clan leaderboard.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SealSummary {
+    pub completed_seals: usize,
+    pub total_seals: usize,
+    pub completed_triumphs: usize,
+    pub total_triumphs: usize,
+}
+
+impl<'a> TriumphTree<'a> {
+    /// Walks the trees from the roots named in the profile records component, which must have
+    /// been requested. Character-scoped records are read from `character_id`'s records.
+    pub fn new(
+        profile: &'a DestinyProfileResponse,
+        character_id: CharacterId,
+        node_definitions: &'a DestinyPresentationNodeManifest,
+        record_definitions: &'a DestinyRecordManifest,
+    ) -> Result<Self> {
+        let profile_records = profile.profile_records()?;
+        let character_records = profile
+            .character_records()
+            .ok()
+            .and_then(|r| r.get(&character_id));
+        let builder = Builder {
+            node_definitions,
+            record_definitions,
+            profile_records: &profile_records.records,
+            character_records: character_records.map(|r| &r.records),
+        };
+
+        Ok(TriumphTree {
+            seals: builder.node(profile_records.record_seals_root_node_hash, &mut Vec::new()),
+            categories: builder.node(
+                profile_records.record_categories_root_node_hash,
+                &mut Vec::new(),
+            ),
+        })
+    }
+
+    /// The seals, each a child of the seals root with a title record.
+    pub fn titles(&self) -> impl Iterator<Item = &TriumphNode<'a>> {
+        self.seals
+            .iter()
+            .flat_map(|root| &root.children)
+            .filter(|seal| seal.completion_record.is_some())
+    }
+
+    pub fn seal_summary(&self) -> SealSummary {
+        self.titles()
+            .fold(SealSummary::default(), |summary, seal| SealSummary {
+                completed_seals: summary.completed_seals + usize::from(seal.is_complete()),
+                total_seals: summary.total_seals + 1,
+                completed_triumphs: summary.completed_triumphs + seal.completed,
+                total_triumphs: summary.total_triumphs + seal.total,
+            })
+    }
+}
+
+impl<'a> TriumphNode<'a> {
+    /// Whether the completion record is complete, or for nodes without one, every counted
+    /// record below the node.
+    pub fn is_complete(&self) -> bool {
+        match &self.completion_record {
+            Some(record) => record.is_complete(),
+            None => self.total > 0 && self.completed == self.total,
+        }
+    }
+
+    /// The title the seal awards, preferring the `Male` variant when the title is gendered.
+    pub fn title(&self) -> Option<&'a str> {
+        let titles = &self
+            .completion_record
+            .as_ref()?
+            .definition?
+            .title_info
+            .as_ref()
+            .filter(|t| t.has_title)?
+            .titles_by_gender;
+        titles
+            .get("Male")
+            .or_else(|| titles.values().next())
+            .map(String::as_str)
+    }
+
+    /// The counted records below the node that are not yet complete, depth first.
+    pub fn missing(&self) -> Vec<&TriumphRecord<'a>> {
+        let mut missing = self
+            .records
+            .iter()
+            .filter(|r| r.is_counted() && !r.is_complete())
+            .collect::<Vec<_>>();
+        for child in &self.children {
+            missing.extend(child.missing());
+        }
+        missing
+    }
+}
+
+impl TriumphRecord<'_> {
+    pub fn is_complete(&self) -> bool {
+        self.state
+            .is_some_and(|s| !s.contains(DestinyRecordState::ObjectiveNotCompleted))
+    }
+
+    /// Invisible records are left out of node counts unless they are complete.
+    pub fn is_counted(&self) -> bool {
+        self.is_complete()
+            || self
+                .state
+                .is_none_or(|s| !s.contains(DestinyRecordState::Invisible))
+    }
+}
+
+/// Orders members by completed seals, then completed seal triumphs, most first. Ties keep their
+/// order.
+pub fn rank_by_seals<K>(members: &mut [(K, SealSummary)]) {
+    members.sort_by_key(|(_, s)| Reverse((s.completed_seals, s.completed_triumphs)));
+}
+
+struct Builder<'a> {
+    node_definitions: &'a DestinyPresentationNodeManifest,
+    record_definitions: &'a DestinyRecordManifest,
+    profile_records: &'a HashMap<u32, DestinyRecordComponent>,
+    character_records: Option<&'a HashMap<u32, DestinyRecordComponent>>,
+}
+
+impl<'a> Builder<'a> {
+    /// `path` holds the nodes above this one, so that a node listed as its own descendant is
+    /// not walked again.
+    fn node(&self, node_hash: u32, path: &mut Vec<u32>) -> Option<TriumphNode<'a>> {
+        if path.contains(&node_hash) {
+            return None;
+        }
+        let definition = self.node_definitions.get(&node_hash.to_string())?;
+
+        path.push(node_hash);
+        let children = definition
+            .children
+            .presentation_nodes
+            .iter()
+            .filter_map(|c| self.node(c.presentation_node_hash, path))
+            .collect::<Vec<_>>();
+        path.pop();
+
+        let records = definition
+            .children
+            .records
+            .iter()
+            .map(|r| self.record(r.record_hash))
+            .collect::<Vec<_>>();
+        let counted = records.iter().filter(|r| r.is_counted());
+
+        Some(TriumphNode {
+            node_hash,
+            definition,
+            completed: counted.clone().filter(|r| r.is_complete()).count()
+                + children.iter().map(|c| c.completed).sum::<usize>(),
+            total: counted.count() + children.iter().map(|c| c.total).sum::<usize>(),
+            children,
+            records,
+            completion_record: definition.completion_record_hash.map(|h| self.record(h)),
+        })
+    }
+
+    fn record(&self, record_hash: u32) -> TriumphRecord<'a> {
+        let component = self
+            .profile_records
+            .get(&record_hash)
+            .or_else(|| self.character_records?.get(&record_hash));
+
+        TriumphRecord {
+            record_hash,
+            definition: self.record_definitions.get(&record_hash.to_string()),
+            state: component.map(|c| c.state),
+            objectives: component.map_or(&[], |c| &c.objectives),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SealSummary, TriumphTree, rank_by_seals};
+    use crate::test_fixtures::{definitions, profile};
+    use crate::types::ids::CharacterId;
+    use crate::{DestinyPresentationNodeManifest, DestinyRecordManifest};
+
+    #[test]
+    fn triumph_tree() {
+        let profile = profile("profile.json");
+        let nodes: DestinyPresentationNodeManifest =
+            definitions("DestinyPresentationNodeDefinition");
+        let records: DestinyRecordManifest = definitions("DestinyRecordDefinition");
+
+        let tree =
+            TriumphTree::new(&profile, CharacterId(2305843009300000001), &nodes, &records).unwrap();
+
+        let seal = tree.titles().next().unwrap();
+        assert_eq!(seal.definition.display_properties.name, "Sample Seal");
+        assert_eq!(seal.title(), Some("Sampler"));
+        assert_eq!((seal.completed, seal.total), (1, 2));
+        assert!(!seal.is_complete());
+        let missing = seal
+            .missing()
+            .iter()
+            .map(|r| r.record_hash)
+            .collect::<Vec<_>>();
+        assert_eq!(missing, [1314668818]);
+        assert_eq!(seal.records[0].objectives[0].progress, 42);
+
+        let categories = tree.categories.as_ref().unwrap();
+        assert_eq!((categories.completed, categories.total), (1, 2));
+        assert_eq!(categories.children[0].missing()[0].record_hash, 1458880365);
+
+        let summary = tree.seal_summary();
+        assert_eq!(
+            summary,
+            SealSummary {
+                completed_seals: 0,
+                total_seals: 1,
+                completed_triumphs: 1,
+                total_triumphs: 2,
+            }
+        );
+
+        let mut members = [
+            ("a", summary),
+            (
+                "b",
+                SealSummary {
+                    completed_seals: 1,
+                    ..summary
+                },
+            ),
+            ("c", summary),
+        ];
+        rank_by_seals(&mut members);
+        assert_eq!(members.map(|(name, _)| name), ["b", "a", "c"]);
+    }
+}
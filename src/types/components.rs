@@ -1,44 +1,56 @@
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+mod generated;
+pub use generated::*;
 
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// A single component of a response. Bungie leaves `data` out when the component is private to
+/// the caller or disabled, so read it through [`ComponentResponse::data`] to learn why.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ComponentResponse<T> {
-    pub data: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<T>,
     pub privacy: ComponentPrivacySetting,
     #[serde(default)]
     pub disabled: bool,
 }
 
-#[repr(u8)]
-#[derive(Debug, Copy, Clone)]
-pub enum ComponentPrivacySetting {
-    None = 0,
-    Public = 1,
-    Private = 2,
-}
-
-impl<'de> Deserialize<'de> for ComponentPrivacySetting {
-    fn deserialize<D>(deserializer: D) -> Result<ComponentPrivacySetting, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let s = u8::deserialize(deserializer)?;
-        match s {
-            0 => Ok(ComponentPrivacySetting::None),
-            1 => Ok(ComponentPrivacySetting::Public),
-            2 => Ok(ComponentPrivacySetting::Private),
-            _ => Err(serde::de::Error::custom(format!(
-                "unknown ComponentPrivacySetting: {s}"
-            ))),
+impl<T> ComponentResponse<T> {
+    pub fn data(&self) -> Result<&T, ComponentUnavailable> {
+        match &self.data {
+            Some(data) => Ok(data),
+            None if self.disabled => Err(ComponentUnavailable::Disabled),
+            None if matches!(self.privacy, ComponentPrivacySetting::Private) => {
+                Err(ComponentUnavailable::Private)
+            }
+            None => Err(ComponentUnavailable::Missing),
         }
     }
 }
 
-impl Serialize for ComponentPrivacySetting {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        let s = *self as u8;
-        s.serialize(serializer)
+/// Why a component has no data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComponentUnavailable {
+    /// The component was not in the requested `components`.
+    NotRequested,
+    /// The player's privacy settings hide it from the caller.
+    Private,
+    /// Bungie has temporarily disabled the component.
+    Disabled,
+    /// Bungie returned the component without data and without saying why.
+    Missing,
+}
+
+impl fmt::Display for ComponentUnavailable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ComponentUnavailable::NotRequested => "component was not requested",
+            ComponentUnavailable::Private => "component is hidden by the player's privacy settings",
+            ComponentUnavailable::Disabled => "component is disabled by Bungie",
+            ComponentUnavailable::Missing => "component has no data",
+        })
     }
 }
+
+impl std::error::Error for ComponentUnavailable {}
@@ -0,0 +1,53 @@
+// @generated by `cargo xtask codegen`. Do not edit by hand.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ComponentPrivacySetting {
+    None,
+    Public,
+    Private,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for ComponentPrivacySetting {
+    fn deserialize<D>(deserializer: D) -> Result<ComponentPrivacySetting, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(ComponentPrivacySetting::None),
+            1 => Ok(ComponentPrivacySetting::Public),
+            2 => Ok(ComponentPrivacySetting::Private),
+            _ => Ok(ComponentPrivacySetting::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for ComponentPrivacySetting {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<ComponentPrivacySetting> for i32 {
+    fn from(value: ComponentPrivacySetting) -> i32 {
+        match value {
+            ComponentPrivacySetting::None => 0,
+            ComponentPrivacySetting::Public => 1,
+            ComponentPrivacySetting::Private => 2,
+            ComponentPrivacySetting::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for ComponentPrivacySetting {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
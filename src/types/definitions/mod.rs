@@ -21,7 +21,7 @@ use super::{BungieMembershipType, TierType};
 
 #[derive(Debug, Default, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyInventoryItemDefinition {
     pub display_properties: DestinyDisplayPropertiesDefinition,
     #[serde(default)]
@@ -108,7 +108,7 @@ pub struct DestinyInventoryItemDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemTooltipNotification {
     pub display_string: String,
     pub display_style: String,
@@ -116,7 +116,7 @@ pub struct DestinyItemTooltipNotification {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemActionBlockDefinition {
     pub verb_name: String,
     pub verb_description: String,
@@ -140,7 +140,7 @@ pub struct DestinyItemActionBlockDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemActionRequiredItemDefinition {
     pub count: i32,
     pub item_hash: u32,
@@ -149,7 +149,7 @@ pub struct DestinyItemActionRequiredItemDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyProgressionRewardDefinition {
     pub progression_mapping_hash: u32,
     pub amount: i32,
@@ -158,7 +158,7 @@ pub struct DestinyProgressionRewardDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemCraftingBlockDefinition {
     pub output_item_hash: u32,
     pub required_socket_type_hashes: Vec<u32>,
@@ -169,7 +169,7 @@ pub struct DestinyItemCraftingBlockDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemCraftingBlockBonusPlugDefinition {
     pub socket_type_hash: u32,
     pub plug_item_hash: u32,
@@ -177,7 +177,7 @@ pub struct DestinyItemCraftingBlockBonusPlugDefinition {
 
 #[derive(Debug, Default, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemInventoryBlockDefinition {
     pub stack_unique_label: Option<String>,
     pub max_stack_size: i32,
@@ -197,7 +197,7 @@ pub struct DestinyItemInventoryBlockDefinition {
 
 #[derive(Debug, Default, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemSetBlockDefinition {
     pub item_list: Vec<DestinyItemSetBlockEntryDefinition>,
     pub tracking_unlock_value_hash: u32,
@@ -212,7 +212,7 @@ pub struct DestinyItemSetBlockDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemSetBlockEntryDefinition {
     pub tracking_value: i32,
     pub item_hash: u32,
@@ -220,7 +220,7 @@ pub struct DestinyItemSetBlockEntryDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemStatBlockDefinition {
     pub disable_primary_stat_display: bool,
     pub stat_group_hash: Option<u32>,
@@ -231,7 +231,7 @@ pub struct DestinyItemStatBlockDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyInventoryItemStatDefinition {
     pub stat_hash: u32,
     pub value: i32,
@@ -242,7 +242,7 @@ pub struct DestinyInventoryItemStatDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyEquippingBlockDefinition {
     pub gearset_item_hash: Option<u32>,
     pub unique_label: Option<String>,
@@ -258,7 +258,7 @@ pub struct DestinyEquippingBlockDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemTranslationBlockDefinition {
     pub weapon_pattern_identifier: Option<String>,
     pub weapon_pattern_hash: u32,
@@ -271,7 +271,7 @@ pub struct DestinyItemTranslationBlockDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyGearArtArrangementReference {
     pub class_hash: u32,
     pub art_arrangement_hash: u32,
@@ -279,7 +279,7 @@ pub struct DestinyGearArtArrangementReference {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemPreviewBlockDefinition {
     pub screen_style: String,
     pub preview_vendor_hash: u32,
@@ -291,7 +291,7 @@ pub struct DestinyItemPreviewBlockDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemQualityBlockDefinition {
     pub item_levels: Vec<i32>,
     pub quality_level: i32,
@@ -306,14 +306,14 @@ pub struct DestinyItemQualityBlockDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemVersionDefinition {
     pub power_cap_hash: u32,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemValueBlockDefinition {
     pub item_value: Vec<DestinyItemQuantity>,
     pub value_description: String,
@@ -321,7 +321,7 @@ pub struct DestinyItemValueBlockDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemSourceBlockDefinition {
     pub source_hashes: Vec<u32>,
     pub sources: Vec<DestinyItemSourceDefinition>,
@@ -331,7 +331,7 @@ pub struct DestinyItemSourceBlockDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemVendorSourceReference {
     pub vendor_hash: u32,
     pub vendor_item_indexes: Vec<i32>,
@@ -339,7 +339,7 @@ pub struct DestinyItemVendorSourceReference {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemObjectiveBlockDefinition {
     pub objective_hashes: Vec<u32>,
     pub display_activity_hashes: Vec<u32>,
@@ -361,7 +361,7 @@ pub struct DestinyItemObjectiveBlockDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyObjectiveDisplayProperties {
     pub activity_hash: Option<u32>,
     pub display_on_item_preview_screen: bool,
@@ -369,14 +369,14 @@ pub struct DestinyObjectiveDisplayProperties {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemMetricBlockDefinition {
     pub available_metric_category_node_hashes: Vec<u32>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemGearsetBlockDefinition {
     pub tracking_value_max: i32,
     pub item_list: Vec<u32>,
@@ -384,7 +384,7 @@ pub struct DestinyItemGearsetBlockDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemSackBlockDefinition {
     pub detail_action: String,
     pub open_action: String,
@@ -400,7 +400,7 @@ pub struct DestinyItemSackBlockDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemSocketBlockDefinition {
     pub detail: String,
     pub socket_entries: Vec<DestinyItemSocketEntryDefinition>,
@@ -410,7 +410,7 @@ pub struct DestinyItemSocketBlockDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemSocketEntryDefinition {
     pub socket_type_hash: u32,
     pub single_initial_item_hash: u32,
@@ -427,14 +427,14 @@ pub struct DestinyItemSocketEntryDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemSocketEntryPlugItemDefinition {
     pub plug_item_hash: u32,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemIntrinsicSocketEntryDefinition {
     pub plug_item_hash: u32,
     pub socket_type_hash: u32,
@@ -443,7 +443,7 @@ pub struct DestinyItemIntrinsicSocketEntryDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemSocketCategoryDefinition {
     pub socket_category_hash: u32,
     pub socket_indexes: Vec<i32>,
@@ -451,14 +451,14 @@ pub struct DestinyItemSocketCategoryDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemSummaryBlockDefinition {
     pub sort_priority: i32,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemTalentGridBlockDefinition {
     pub talent_grid_hash: u32,
     pub item_detail_string: Option<String>,
@@ -469,7 +469,7 @@ pub struct DestinyItemTalentGridBlockDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemInvestmentStatDefinition {
     pub stat_type_hash: u32,
     pub value: i32,
@@ -478,7 +478,7 @@ pub struct DestinyItemInvestmentStatDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemPerkEntryDefinition {
     pub requirement_display_string: String,
     pub perk_hash: u32,
@@ -5,7 +5,7 @@ use super::DestinyInventoryItemStatDefinition;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemSourceDefinition {
     pub level: i32,
     pub min_quality: i32,
@@ -4,7 +4,7 @@ use super::DestinyProgression;
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyArtifactProfileScoped {
     pub artifact_hash: u32,
     pub point_progression: DestinyProgression,
@@ -15,7 +15,7 @@ pub struct DestinyArtifactProfileScoped {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyArtifactCharacterScoped {
     pub artifact_hash: u32,
     pub points_used: i32,
@@ -25,7 +25,7 @@ pub struct DestinyArtifactCharacterScoped {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyArtifactTier {
     pub tier_hash: u32,
     pub is_unlocked: bool,
@@ -35,7 +35,7 @@ pub struct DestinyArtifactTier {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyArtifactTierItem {
     pub item_hash: u32,
     pub is_active: bool,
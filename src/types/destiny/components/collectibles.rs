@@ -5,7 +5,7 @@ use crate::types::destiny::DestinyCollectibleState;
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyCollectiblesComponent {
     pub collectibles: HashMap<u32, DestinyCollectibleComponent>,
     pub collection_categories_root_node_hash: u32,
@@ -20,7 +20,7 @@ pub struct DestinyCollectibleComponent {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyProfileCollectiblesComponent {
     pub recent_collectible_hashes: Vec<u32>,
     pub newness_flagged_collectible_hashes: Vec<u32>,
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyCraftablesComponent {
     pub craftables: HashMap<u32, DestinyCraftableComponent>,
     pub crafting_root_node_hash: u32,
@@ -11,7 +11,7 @@ pub struct DestinyCraftablesComponent {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyCraftableComponent {
     pub visible: bool,
     pub failed_requirement_indexes: Vec<i32>,
@@ -20,7 +20,7 @@ pub struct DestinyCraftableComponent {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyCraftableSocketComponent {
     pub plug_set_hash: u32,
     pub plugs: Vec<DestinyCraftableSocketPlugComponent>,
@@ -28,7 +28,7 @@ pub struct DestinyCraftableSocketComponent {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyCraftableSocketPlugComponent {
     pub plug_item_hash: u32,
     pub failed_requirement_indexes: Vec<i32>,
@@ -1,3 +1,4 @@
+use crate::types::ids::ItemInstanceId;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -7,7 +8,7 @@ pub struct DestinyLoadoutsComponent {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyLoadoutComponent {
     pub color_hash: u32,
     pub icon_hash: u32,
@@ -17,8 +18,8 @@ pub struct DestinyLoadoutComponent {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyLoadoutItemComponent {
-    pub item_instance_id: i64,
+    pub item_instance_id: ItemInstanceId,
     pub plug_item_hashes: Vec<u32>,
 }
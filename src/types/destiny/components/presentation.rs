@@ -11,7 +11,7 @@ pub struct DestinyPresentationNodesComponent {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyPresentationNodeComponent {
     pub state: DestinyPresentationNodeState,
     pub objective: DestinyObjectiveProgress,
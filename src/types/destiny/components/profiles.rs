@@ -6,10 +6,11 @@ use crate::types::destiny::artifacts::DestinyArtifactProfileScoped;
 use crate::types::destiny::{
     DestinyGamePrivacySetting, DestinyJoinClosedReasons, DestinyPartyMemberStates,
 };
+use crate::types::ids::MembershipId;
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyProfileProgressionComponent {
     pub checklists: HashMap<u32, HashMap<u32, bool>>,
     pub seasonal_artifact: DestinyArtifactProfileScoped,
@@ -17,7 +18,7 @@ pub struct DestinyProfileProgressionComponent {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyProfileTransitoryComponent {
     pub party_members: Vec<DestinyProfileTransitoryPartyMember>,
     pub current_activity: DestinyProfileTransitoryCurrentActivity,
@@ -28,9 +29,9 @@ pub struct DestinyProfileTransitoryComponent {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyProfileTransitoryPartyMember {
-    pub membership_id: i64,
+    pub membership_id: MembershipId,
     pub emblem_hash: u32,
     pub display_name: String,
     pub status: DestinyPartyMemberStates,
@@ -38,7 +39,7 @@ pub struct DestinyProfileTransitoryPartyMember {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyProfileTransitoryCurrentActivity {
     pub start_time: DateTime<Utc>,
     pub end_time: DateTime<Utc>,
@@ -50,7 +51,7 @@ pub struct DestinyProfileTransitoryCurrentActivity {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyProfileTransitoryJoinability {
     pub open_slots: i32,
     pub privacy_setting: DestinyGamePrivacySetting,
@@ -59,7 +60,7 @@ pub struct DestinyProfileTransitoryJoinability {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyProfileTransitoryTrackingEntry {
     pub location_hash: u32,
     pub item_hash: u32,
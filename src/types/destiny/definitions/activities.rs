@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::common::DestinyDisplayPropertiesDefinition;
+use crate::types::destiny::historical_stats::definitions::DestinyActivityModeType;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyActivityDefinition {
+    pub display_properties: DestinyDisplayPropertiesDefinition,
+    pub original_display_properties: Option<DestinyDisplayPropertiesDefinition>,
+    pub selection_screen_display_properties: Option<DestinyDisplayPropertiesDefinition>,
+    pub release_icon: Option<String>,
+    pub release_time: i32,
+    pub activity_light_level: i32,
+    pub destination_hash: u32,
+    pub place_hash: u32,
+    pub activity_type_hash: u32,
+    pub tier: i32,
+    pub pgcr_image: Option<String>,
+    #[serde(default)]
+    pub modifiers: Vec<DestinyActivityModifierReferenceDefinition>,
+    pub is_playlist: bool,
+    pub direct_activity_mode_hash: Option<u32>,
+    pub direct_activity_mode_type: Option<DestinyActivityModeType>,
+    #[serde(default)]
+    pub activity_mode_hashes: Vec<u32>,
+    #[serde(default)]
+    pub activity_mode_types: Vec<DestinyActivityModeType>,
+    pub is_pv_p: bool,
+    pub hash: u32,
+    pub index: i32,
+    pub redacted: bool,
+    pub blacklisted: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyActivityModifierReferenceDefinition {
+    pub activity_modifier_hash: u32,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyActivityModifierDefinition {
+    pub display_properties: DestinyDisplayPropertiesDefinition,
+    pub display_in_nav_mode: bool,
+    pub display_in_activity_selection: bool,
+    pub hash: u32,
+    pub index: i32,
+    pub redacted: bool,
+    pub blacklisted: bool,
+}
@@ -0,0 +1,25 @@
+// @generated by `cargo xtask codegen`. Do not edit by hand.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyDamageTypeDefinition {
+    /// A color associated with the damage type. The displayProperties icon is tinted with a color close to this.
+    pub color: crate::types::misc::DestinyColor,
+    /// The description of the damage type, icon etc...
+    pub display_properties: crate::types::common::DestinyDisplayPropertiesDefinition,
+    /// We have an enumeration for damage types for quick reference. This is the current definition's damage type enum value.
+    pub enum_value: crate::types::destiny::DamageType,
+    /// The unique identifier for this entity. Guaranteed to be unique for the type of entity, but not globally.
+    pub hash: u32,
+    /// The index of the entity as it was found in the investment tables.
+    pub index: i32,
+    /// If this is true, then there is an entity with this identifier/type combination, but BNet is not yet allowed to show it. Sorry!
+    pub redacted: bool,
+    /// If TRUE, the game shows this damage type's icon. Otherwise, it doesn't. Whether you show it or not is up to you.
+    pub show_icon: bool,
+    /// A variant of the icon that is transparent and colorless.
+    pub transparent_icon_path: String,
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::common::DestinyDisplayPropertiesDefinition;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyMilestoneDefinition {
+    pub display_properties: DestinyDisplayPropertiesDefinition,
+    pub image: Option<String>,
+    pub friendly_name: Option<String>,
+    pub show_in_explorer: bool,
+    pub show_in_milestones: bool,
+    pub explore_prioritizes_activity_image: bool,
+    pub has_predictable_dates: bool,
+    pub is_in_game_milestone: bool,
+    pub default_order: i32,
+    pub hash: u32,
+    pub index: i32,
+    pub redacted: bool,
+    pub blacklisted: bool,
+}
@@ -1,10 +1,17 @@
 use serde::{Deserialize, Serialize};
 
+pub mod activities;
+mod generated;
+pub mod milestones;
+pub mod presentation;
+pub mod records;
 pub mod sockets;
+pub mod vendors;
+pub use generated::*;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyMaterialRequirement {
     pub item_hash: u32,
     pub delete_on_action: bool,
@@ -15,7 +22,7 @@ pub struct DestinyMaterialRequirement {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemSocketEntryPlugItemRandomizedDefinition {
     pub crafting_requirements: Option<DestinyPlugItemCraftingRequirements>,
     pub weight: f32,
@@ -26,7 +33,7 @@ pub struct DestinyItemSocketEntryPlugItemRandomizedDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyPlugItemCraftingRequirements {
     pub unlock_requirements: Vec<DestinyPlugItemCraftingUnlockRequirement>,
     pub required_level: Option<i32>,
@@ -35,7 +42,7 @@ pub struct DestinyPlugItemCraftingRequirements {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyPlugItemCraftingUnlockRequirement {
     pub failure_description: String,
 }
@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::common::DestinyDisplayPropertiesDefinition;
+use crate::types::destiny::{
+    DestinyPresentationDisplayStyle, DestinyPresentationNodeType, DestinyPresentationScreenStyle,
+    DestinyScope,
+};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyPresentationNodeDefinition {
+    pub display_properties: DestinyDisplayPropertiesDefinition,
+    /// The icon before any overrides, such as a seal's gilded icon.
+    pub original_icon: Option<String>,
+    /// Shown when the node is the root of a screen.
+    pub root_view_icon: Option<String>,
+    pub node_type: DestinyPresentationNodeType,
+    pub is_seasonal: bool,
+    /// Whether the node's progress is tracked for the whole profile or per character.
+    pub scope: DestinyScope,
+    /// An objective summarizing the node's progress, when Bungie provides one.
+    pub objective_hash: Option<u32>,
+    /// The record completed with the node, such as a seal's title.
+    pub completion_record_hash: Option<u32>,
+    #[serde(default)]
+    pub children: DestinyPresentationNodeChildrenBlock,
+    pub display_style: DestinyPresentationDisplayStyle,
+    pub screen_style: DestinyPresentationScreenStyle,
+    pub requirements: Option<DestinyPresentationNodeRequirementsBlock>,
+    pub disable_child_subscreen_navigation: bool,
+    pub max_category_record_score: i32,
+    pub presentation_node_type: DestinyPresentationNodeType,
+    #[serde(default)]
+    pub trait_ids: Vec<String>,
+    #[serde(default)]
+    pub trait_hashes: Vec<u32>,
+    #[serde(default)]
+    pub parent_node_hashes: Vec<u32>,
+    pub hash: u32,
+    pub index: i32,
+    pub redacted: bool,
+    pub blacklisted: bool,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyPresentationNodeChildrenBlock {
+    #[serde(default)]
+    pub presentation_nodes: Vec<DestinyPresentationNodeChildEntry>,
+    #[serde(default)]
+    pub collectibles: Vec<DestinyPresentationNodeCollectibleChildEntry>,
+    #[serde(default)]
+    pub records: Vec<DestinyPresentationNodeRecordChildEntry>,
+    #[serde(default)]
+    pub metrics: Vec<DestinyPresentationNodeMetricChildEntry>,
+    #[serde(default)]
+    pub craftables: Vec<DestinyPresentationNodeCraftableChildEntry>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyPresentationNodeChildEntry {
+    pub presentation_node_hash: u32,
+    /// Lower values are shown first.
+    pub node_display_priority: u32,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyPresentationNodeCollectibleChildEntry {
+    pub collectible_hash: u32,
+    pub node_display_priority: u32,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyPresentationNodeRecordChildEntry {
+    pub record_hash: u32,
+    pub node_display_priority: u32,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyPresentationNodeMetricChildEntry {
+    pub metric_hash: u32,
+    pub node_display_priority: u32,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyPresentationNodeCraftableChildEntry {
+    pub craftable_item_hash: u32,
+    pub node_display_priority: u32,
+}
+
+/// Where a record or other child sits in the presentation node tree.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyPresentationChildBlock {
+    pub presentation_node_type: DestinyPresentationNodeType,
+    #[serde(default)]
+    pub parent_presentation_node_hashes: Vec<u32>,
+    pub display_style: DestinyPresentationDisplayStyle,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyPresentationNodeRequirementsBlock {
+    /// Shown when the player lacks the entitlement the node or record needs.
+    pub entitlement_unavailable_message: Option<String>,
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::common::DestinyDisplayPropertiesDefinition;
+use crate::types::destiny::{
+    DestinyItemQuantity, DestinyPresentationNodeType, DestinyRecordToastStyle,
+    DestinyRecordValueStyle, DestinyScope,
+};
+
+use super::presentation::{
+    DestinyPresentationChildBlock, DestinyPresentationNodeRequirementsBlock,
+};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyRecordDefinition {
+    pub display_properties: DestinyDisplayPropertiesDefinition,
+    /// Whether the record is tracked for the whole profile or per character.
+    pub scope: DestinyScope,
+    pub presentation_info: Option<DestinyPresentationChildBlock>,
+    pub lore_hash: Option<u32>,
+    #[serde(default)]
+    pub objective_hashes: Vec<u32>,
+    pub record_value_style: DestinyRecordValueStyle,
+    pub for_title_gilding: bool,
+    pub should_show_large_icons: bool,
+    pub title_info: Option<DestinyRecordTitleBlock>,
+    pub completion_info: Option<DestinyRecordCompletionBlock>,
+    pub state_info: Option<SchemaRecordStateBlock>,
+    pub requirements: Option<DestinyPresentationNodeRequirementsBlock>,
+    pub expiration_info: Option<DestinyRecordExpirationBlock>,
+    /// Set for records whose objectives complete one after another, such as catalysts.
+    pub interval_info: Option<DestinyRecordIntervalBlock>,
+    #[serde(default)]
+    pub reward_items: Vec<DestinyItemQuantity>,
+    pub record_type_name: Option<String>,
+    pub presentation_node_type: DestinyPresentationNodeType,
+    #[serde(default)]
+    pub trait_ids: Vec<String>,
+    #[serde(default)]
+    pub trait_hashes: Vec<u32>,
+    #[serde(default)]
+    pub parent_node_hashes: Vec<u32>,
+    pub hash: u32,
+    pub index: i32,
+    pub redacted: bool,
+    pub blacklisted: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyRecordTitleBlock {
+    pub has_title: bool,
+    /// Keyed by gender name, e.g. `Male`.
+    #[serde(default)]
+    pub titles_by_gender: HashMap<String, String>,
+    /// Keyed by the gender's definition hash.
+    #[serde(default)]
+    pub titles_by_gender_hash: HashMap<u32, String>,
+    /// The record that tracks how often the title has been gilded.
+    pub gilding_tracking_record_hash: Option<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyRecordCompletionBlock {
+    pub partial_completion_objective_count_threshold: i32,
+    /// Bungie capitalizes this field, unlike its neighbours.
+    #[serde(rename = "ScoreValue")]
+    pub score_value: i32,
+    pub should_fire_toast: bool,
+    pub toast_style: DestinyRecordToastStyle,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct SchemaRecordStateBlock {
+    pub featured_priority: i32,
+    /// Shown in place of the name while the record is obscured.
+    pub obscured_name: Option<String>,
+    pub obscured_description: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyRecordExpirationBlock {
+    pub has_expiration: bool,
+    pub description: Option<String>,
+    pub icon: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyRecordIntervalBlock {
+    #[serde(default)]
+    pub interval_objectives: Vec<DestinyRecordIntervalObjective>,
+    #[serde(default)]
+    pub interval_rewards: Vec<DestinyRecordIntervalRewards>,
+    pub original_objective_array_insertion_index: i32,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyRecordIntervalObjective {
+    pub interval_objective_hash: u32,
+    pub interval_score_value: i32,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyRecordIntervalRewards {
+    #[serde(default)]
+    pub interval_reward_items: Vec<DestinyItemQuantity>,
+}
@@ -9,7 +9,7 @@ use super::DestinyItemSocketEntryPlugItemRandomizedDefinition;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinySocketTypeDefinition {
     pub display_properties: DestinyDisplayPropertiesDefinition,
     pub insert_action: Option<DestinyInsertPlugActionDefinition>,
@@ -32,7 +32,7 @@ pub struct DestinySocketTypeDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyInsertPlugActionDefinition {
     pub action_execute_seconds: i32,
     pub action_sound_hash: u32,
@@ -42,7 +42,7 @@ pub struct DestinyInsertPlugActionDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyPlugWhitelistEntryDefinition {
     pub category_hash: u32,
     pub category_identifier: String,
@@ -52,7 +52,7 @@ pub struct DestinyPlugWhitelistEntryDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinySocketTypeScalarMaterialRequirementEntry {
     pub currency_item_hash: u32,
     pub scalar_value: i32,
@@ -60,7 +60,7 @@ pub struct DestinySocketTypeScalarMaterialRequirementEntry {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinySocketCategoryDefinition {
     pub display_properties: DestinyDisplayPropertiesDefinition,
     pub ui_category_style: u32,
@@ -73,7 +73,7 @@ pub struct DestinySocketCategoryDefinition {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyPlugSetDefinition {
     pub display_properties: Option<DestinyDisplayPropertiesDefinition>,
     #[serde(default)]
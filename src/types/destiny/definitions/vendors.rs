@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyVendorDefinition {
+    pub display_properties: DestinyVendorDisplayPropertiesDefinition,
+    pub vendor_subcategory_identifier: Option<String>,
+    pub display_item_hash: u32,
+    pub inhibit_buying: bool,
+    pub inhibit_selling: bool,
+    pub faction_hash: u32,
+    pub enabled: bool,
+    pub visible: bool,
+    pub vendor_identifier: Option<String>,
+    pub vendor_portrait: Option<String>,
+    pub vendor_banner: Option<String>,
+    pub hash: u32,
+    pub index: i32,
+    pub redacted: bool,
+    pub blacklisted: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinyVendorDisplayPropertiesDefinition {
+    pub large_icon: Option<String>,
+    pub subtitle: Option<String>,
+    pub original_icon: Option<String>,
+    pub small_transparent_icon: Option<String>,
+    pub map_icon: Option<String>,
+    pub large_transparent_icon: Option<String>,
+    pub description: String,
+    pub name: String,
+    pub icon: Option<String>,
+    pub has_icon: bool,
+}
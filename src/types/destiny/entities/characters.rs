@@ -15,14 +15,15 @@ use crate::types::destiny::{
 use crate::types::misc::DestinyColor;
 
 use super::items::DestinyItemPerksComponent;
+use crate::types::ids::{CharacterId, MembershipId};
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyCharacterComponent {
-    pub membership_id: i64,
+    pub membership_id: MembershipId,
     pub membership_type: BungieMembershipType,
-    pub character_id: i64,
+    pub character_id: CharacterId,
     pub date_last_played: DateTime<Utc>,
     pub minutes_played_this_session: i64,
     pub minutes_played_total: i64,
@@ -46,7 +47,7 @@ pub struct DestinyCharacterComponent {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyCharacterProgressionComponent {
     pub progressions: HashMap<u32, DestinyProgression>,
     pub factions: HashMap<u32, DestinyFactionProgression>,
@@ -60,7 +61,7 @@ pub struct DestinyCharacterProgressionComponent {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyCharacterRenderComponent {
     pub custom_dyes: Vec<DyeReference>,
     pub customization: DestinyCharacterCustomization,
@@ -69,7 +70,7 @@ pub struct DestinyCharacterRenderComponent {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyCharacterActivitiesComponent {
     pub date_activity_started: DateTime<Utc>,
     pub available_activities: Vec<DestinyActivity>,
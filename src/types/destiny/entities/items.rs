@@ -10,13 +10,14 @@ use crate::types::destiny::{
     DamageType, DestinyEnergyType, DestinyProgression, DestinyStat, DestinyTalentNode,
     EquipFailureReason, ItemBindStatus, ItemState, TransferStatuses,
 };
+use crate::types::ids::ItemInstanceId;
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemComponent {
     pub item_hash: u32,
-    pub item_instance_id: i64,
+    pub item_instance_id: Option<ItemInstanceId>,
     pub quantity: i32,
     pub bind_status: ItemBindStatus,
     pub location: ItemLocation,
@@ -24,12 +25,12 @@ pub struct DestinyItemComponent {
     pub transfer_status: TransferStatuses,
     pub lockable: bool,
     pub state: ItemState,
-    pub override_style_item_hash: u32,
-    pub expiration_date: DateTime<Utc>,
+    pub override_style_item_hash: Option<u32>,
+    pub expiration_date: Option<DateTime<Utc>>,
     pub is_wrapper: bool,
     pub tooltip_notification_indexes: Vec<i32>,
-    pub metric_hash: u32,
-    pub metric_objective: DestinyObjectiveProgress,
+    pub metric_hash: Option<u32>,
+    pub metric_objective: Option<DestinyObjectiveProgress>,
     pub version_number: i32,
     pub item_value_visibility: Vec<bool>,
 }
@@ -41,7 +42,7 @@ pub struct DestinyItemPerksComponent {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemObjectivesComponent {
     pub objectives: Vec<DestinyObjectiveProgress>,
     pub flavor_objective: DestinyObjectiveProgress,
@@ -50,10 +51,10 @@ pub struct DestinyItemObjectivesComponent {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemInstanceComponent {
     pub damage_type: DamageType,
-    pub damage_type_hash: u32,
+    pub damage_type_hash: Option<u32>,
     pub primary_stat: DestinyStat,
     pub item_level: i32,
     pub quality: i32,
@@ -63,13 +64,13 @@ pub struct DestinyItemInstanceComponent {
     pub unlock_hashes_required_to_equip: Vec<u32>,
     pub cannot_equip_reason: EquipFailureReason,
     pub breaker_type: i32,
-    pub breaker_type_hash: u32,
-    pub energy: DestinyItemInstanceEnergy,
+    pub breaker_type_hash: Option<u32>,
+    pub energy: Option<DestinyItemInstanceEnergy>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemInstanceEnergy {
     pub energy_type_hash: u32,
     pub energy_type: DestinyEnergyType,
@@ -80,7 +81,7 @@ pub struct DestinyItemInstanceEnergy {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemRenderComponent {
     pub use_custom_dyes: bool,
     pub art_regions: HashMap<i32, i32>,
@@ -98,7 +99,7 @@ pub struct DestinyItemSocketsComponent {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemSocketState {
     pub plug_hash: u32,
     pub is_enabled: bool,
@@ -108,7 +109,7 @@ pub struct DestinyItemSocketState {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemTalentGridComponent {
     pub talent_grid_hash: u32,
     pub nodes: Vec<DestinyTalentNode>,
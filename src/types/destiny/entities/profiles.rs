@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::types::destiny::DestinyGameVersions;
 use crate::types::destiny::vendors::DestinyVendorReceipt;
+use crate::types::ids::CharacterId;
 use crate::types::user::UserInfoCard;
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -12,12 +13,12 @@ pub struct DestinyVendorReceiptsComponent {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyProfileComponent {
     pub user_info: UserInfoCard,
     pub date_last_played: DateTime<Utc>,
     pub versions_owned: DestinyGameVersions,
-    pub character_ids: Vec<String>,
+    pub character_ids: Vec<CharacterId>,
     pub season_hashes: Vec<u32>,
     pub event_card_hashes_owned: Vec<u32>,
     pub current_season_hash: u32,
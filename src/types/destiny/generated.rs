@@ -0,0 +1,2685 @@
+// @generated by `cargo xtask codegen`. Do not edit by hand.
+
+use bitflags::bitflags;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BucketCategory {
+    Invisible,
+    Item,
+    Currency,
+    Equippable,
+    Ignored,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for BucketCategory {
+    fn deserialize<D>(deserializer: D) -> Result<BucketCategory, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(BucketCategory::Invisible),
+            1 => Ok(BucketCategory::Item),
+            2 => Ok(BucketCategory::Currency),
+            3 => Ok(BucketCategory::Equippable),
+            4 => Ok(BucketCategory::Ignored),
+            _ => Ok(BucketCategory::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for BucketCategory {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<BucketCategory> for i32 {
+    fn from(value: BucketCategory) -> i32 {
+        match value {
+            BucketCategory::Invisible => 0,
+            BucketCategory::Item => 1,
+            BucketCategory::Currency => 2,
+            BucketCategory::Equippable => 3,
+            BucketCategory::Ignored => 4,
+            BucketCategory::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for BucketCategory {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BucketScope {
+    Character,
+    Account,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for BucketScope {
+    fn deserialize<D>(deserializer: D) -> Result<BucketScope, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(BucketScope::Character),
+            1 => Ok(BucketScope::Account),
+            _ => Ok(BucketScope::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for BucketScope {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<BucketScope> for i32 {
+    fn from(value: BucketScope) -> i32 {
+        match value {
+            BucketScope::Character => 0,
+            BucketScope::Account => 1,
+            BucketScope::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for BucketScope {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DamageType {
+    None,
+    Kinetic,
+    Arc,
+    Thermal,
+    Void,
+    Raid,
+    Stasis,
+    Strand,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DamageType {
+    fn deserialize<D>(deserializer: D) -> Result<DamageType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DamageType::None),
+            1 => Ok(DamageType::Kinetic),
+            2 => Ok(DamageType::Arc),
+            3 => Ok(DamageType::Thermal),
+            4 => Ok(DamageType::Void),
+            5 => Ok(DamageType::Raid),
+            6 => Ok(DamageType::Stasis),
+            7 => Ok(DamageType::Strand),
+            _ => Ok(DamageType::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DamageType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DamageType> for i32 {
+    fn from(value: DamageType) -> i32 {
+        match value {
+            DamageType::None => 0,
+            DamageType::Kinetic => 1,
+            DamageType::Arc => 2,
+            DamageType::Thermal => 3,
+            DamageType::Void => 4,
+            DamageType::Raid => 5,
+            DamageType::Stasis => 6,
+            DamageType::Strand => 7,
+            DamageType::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DamageType {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyActivityDifficultyTier {
+    Trivial,
+    Easy,
+    Normal,
+    Challenging,
+    Hard,
+    Brave,
+    AlmostImpossible,
+    Impossible,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyActivityDifficultyTier {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyActivityDifficultyTier, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyActivityDifficultyTier::Trivial),
+            1 => Ok(DestinyActivityDifficultyTier::Easy),
+            2 => Ok(DestinyActivityDifficultyTier::Normal),
+            3 => Ok(DestinyActivityDifficultyTier::Challenging),
+            4 => Ok(DestinyActivityDifficultyTier::Hard),
+            5 => Ok(DestinyActivityDifficultyTier::Brave),
+            6 => Ok(DestinyActivityDifficultyTier::AlmostImpossible),
+            7 => Ok(DestinyActivityDifficultyTier::Impossible),
+            _ => Ok(DestinyActivityDifficultyTier::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyActivityDifficultyTier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyActivityDifficultyTier> for i32 {
+    fn from(value: DestinyActivityDifficultyTier) -> i32 {
+        match value {
+            DestinyActivityDifficultyTier::Trivial => 0,
+            DestinyActivityDifficultyTier::Easy => 1,
+            DestinyActivityDifficultyTier::Normal => 2,
+            DestinyActivityDifficultyTier::Challenging => 3,
+            DestinyActivityDifficultyTier::Hard => 4,
+            DestinyActivityDifficultyTier::Brave => 5,
+            DestinyActivityDifficultyTier::AlmostImpossible => 6,
+            DestinyActivityDifficultyTier::Impossible => 7,
+            DestinyActivityDifficultyTier::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyActivityDifficultyTier {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyAmmunitionType {
+    None,
+    Primary,
+    Special,
+    Heavy,
+    Unknown,
+    /// A value not known to this crate. `Unknown` is a value Bungie defines.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyAmmunitionType {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyAmmunitionType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyAmmunitionType::None),
+            1 => Ok(DestinyAmmunitionType::Primary),
+            2 => Ok(DestinyAmmunitionType::Special),
+            3 => Ok(DestinyAmmunitionType::Heavy),
+            4 => Ok(DestinyAmmunitionType::Unknown),
+            _ => Ok(DestinyAmmunitionType::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyAmmunitionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyAmmunitionType> for i32 {
+    fn from(value: DestinyAmmunitionType) -> i32 {
+        match value {
+            DestinyAmmunitionType::None => 0,
+            DestinyAmmunitionType::Primary => 1,
+            DestinyAmmunitionType::Special => 2,
+            DestinyAmmunitionType::Heavy => 3,
+            DestinyAmmunitionType::Unknown => 4,
+            DestinyAmmunitionType::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyAmmunitionType {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyBreakerType {
+    None,
+    ShieldPiercing,
+    Disruption,
+    Stagger,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyBreakerType {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyBreakerType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyBreakerType::None),
+            1 => Ok(DestinyBreakerType::ShieldPiercing),
+            2 => Ok(DestinyBreakerType::Disruption),
+            3 => Ok(DestinyBreakerType::Stagger),
+            _ => Ok(DestinyBreakerType::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyBreakerType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyBreakerType> for i32 {
+    fn from(value: DestinyBreakerType) -> i32 {
+        match value {
+            DestinyBreakerType::None => 0,
+            DestinyBreakerType::ShieldPiercing => 1,
+            DestinyBreakerType::Disruption => 2,
+            DestinyBreakerType::Stagger => 3,
+            DestinyBreakerType::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyBreakerType {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyClass {
+    Titan,
+    Hunter,
+    Warlock,
+    Unknown,
+    /// A value not known to this crate. `Unknown` is a value Bungie defines.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyClass {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyClass, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyClass::Titan),
+            1 => Ok(DestinyClass::Hunter),
+            2 => Ok(DestinyClass::Warlock),
+            3 => Ok(DestinyClass::Unknown),
+            _ => Ok(DestinyClass::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyClass {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyClass> for i32 {
+    fn from(value: DestinyClass) -> i32 {
+        match value {
+            DestinyClass::Titan => 0,
+            DestinyClass::Hunter => 1,
+            DestinyClass::Warlock => 2,
+            DestinyClass::Unknown => 3,
+            DestinyClass::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyClass {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct DestinyCollectibleState: u32 {
+        const NotAcquired = 1;
+        const Obscured = 2;
+        const Invisible = 4;
+        const CannotAffordMaterialRequirements = 8;
+        const InventorySpaceUnavailable = 16;
+        const UniquenessViolation = 32;
+        const PurchaseDisabled = 64;
+    }
+}
+
+impl<'de> Deserialize<'de> for DestinyCollectibleState {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyCollectibleState, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = u32::deserialize(deserializer)?;
+        Ok(DestinyCollectibleState::from_bits_retain(s))
+    }
+}
+
+impl Serialize for DestinyCollectibleState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.bits().serialize(serializer)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyComponentType {
+    None,
+    Profiles,
+    VendorReceipts,
+    ProfileInventories,
+    ProfileCurrencies,
+    ProfileProgression,
+    PlatformSilver,
+    Characters,
+    CharacterInventories,
+    CharacterProgressions,
+    CharacterRenderData,
+    CharacterActivities,
+    CharacterEquipment,
+    CharacterLoadouts,
+    ItemInstances,
+    ItemObjectives,
+    ItemPerks,
+    ItemRenderData,
+    ItemStats,
+    ItemSockets,
+    ItemTalentGrids,
+    ItemCommonData,
+    ItemPlugStates,
+    ItemPlugObjectives,
+    ItemReusablePlugs,
+    Vendors,
+    VendorCategories,
+    VendorSales,
+    Kiosks,
+    CurrencyLookups,
+    PresentationNodes,
+    Collectibles,
+    Records,
+    Transitory,
+    Metrics,
+    StringVariables,
+    Craftables,
+    SocialCommendations,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyComponentType {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyComponentType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyComponentType::None),
+            100 => Ok(DestinyComponentType::Profiles),
+            101 => Ok(DestinyComponentType::VendorReceipts),
+            102 => Ok(DestinyComponentType::ProfileInventories),
+            103 => Ok(DestinyComponentType::ProfileCurrencies),
+            104 => Ok(DestinyComponentType::ProfileProgression),
+            105 => Ok(DestinyComponentType::PlatformSilver),
+            200 => Ok(DestinyComponentType::Characters),
+            201 => Ok(DestinyComponentType::CharacterInventories),
+            202 => Ok(DestinyComponentType::CharacterProgressions),
+            203 => Ok(DestinyComponentType::CharacterRenderData),
+            204 => Ok(DestinyComponentType::CharacterActivities),
+            205 => Ok(DestinyComponentType::CharacterEquipment),
+            206 => Ok(DestinyComponentType::CharacterLoadouts),
+            300 => Ok(DestinyComponentType::ItemInstances),
+            301 => Ok(DestinyComponentType::ItemObjectives),
+            302 => Ok(DestinyComponentType::ItemPerks),
+            303 => Ok(DestinyComponentType::ItemRenderData),
+            304 => Ok(DestinyComponentType::ItemStats),
+            305 => Ok(DestinyComponentType::ItemSockets),
+            306 => Ok(DestinyComponentType::ItemTalentGrids),
+            307 => Ok(DestinyComponentType::ItemCommonData),
+            308 => Ok(DestinyComponentType::ItemPlugStates),
+            309 => Ok(DestinyComponentType::ItemPlugObjectives),
+            310 => Ok(DestinyComponentType::ItemReusablePlugs),
+            400 => Ok(DestinyComponentType::Vendors),
+            401 => Ok(DestinyComponentType::VendorCategories),
+            402 => Ok(DestinyComponentType::VendorSales),
+            500 => Ok(DestinyComponentType::Kiosks),
+            600 => Ok(DestinyComponentType::CurrencyLookups),
+            700 => Ok(DestinyComponentType::PresentationNodes),
+            800 => Ok(DestinyComponentType::Collectibles),
+            900 => Ok(DestinyComponentType::Records),
+            1000 => Ok(DestinyComponentType::Transitory),
+            1100 => Ok(DestinyComponentType::Metrics),
+            1200 => Ok(DestinyComponentType::StringVariables),
+            1300 => Ok(DestinyComponentType::Craftables),
+            1400 => Ok(DestinyComponentType::SocialCommendations),
+            _ => Ok(DestinyComponentType::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyComponentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyComponentType> for i32 {
+    fn from(value: DestinyComponentType) -> i32 {
+        match value {
+            DestinyComponentType::None => 0,
+            DestinyComponentType::Profiles => 100,
+            DestinyComponentType::VendorReceipts => 101,
+            DestinyComponentType::ProfileInventories => 102,
+            DestinyComponentType::ProfileCurrencies => 103,
+            DestinyComponentType::ProfileProgression => 104,
+            DestinyComponentType::PlatformSilver => 105,
+            DestinyComponentType::Characters => 200,
+            DestinyComponentType::CharacterInventories => 201,
+            DestinyComponentType::CharacterProgressions => 202,
+            DestinyComponentType::CharacterRenderData => 203,
+            DestinyComponentType::CharacterActivities => 204,
+            DestinyComponentType::CharacterEquipment => 205,
+            DestinyComponentType::CharacterLoadouts => 206,
+            DestinyComponentType::ItemInstances => 300,
+            DestinyComponentType::ItemObjectives => 301,
+            DestinyComponentType::ItemPerks => 302,
+            DestinyComponentType::ItemRenderData => 303,
+            DestinyComponentType::ItemStats => 304,
+            DestinyComponentType::ItemSockets => 305,
+            DestinyComponentType::ItemTalentGrids => 306,
+            DestinyComponentType::ItemCommonData => 307,
+            DestinyComponentType::ItemPlugStates => 308,
+            DestinyComponentType::ItemPlugObjectives => 309,
+            DestinyComponentType::ItemReusablePlugs => 310,
+            DestinyComponentType::Vendors => 400,
+            DestinyComponentType::VendorCategories => 401,
+            DestinyComponentType::VendorSales => 402,
+            DestinyComponentType::Kiosks => 500,
+            DestinyComponentType::CurrencyLookups => 600,
+            DestinyComponentType::PresentationNodes => 700,
+            DestinyComponentType::Collectibles => 800,
+            DestinyComponentType::Records => 900,
+            DestinyComponentType::Transitory => 1000,
+            DestinyComponentType::Metrics => 1100,
+            DestinyComponentType::StringVariables => 1200,
+            DestinyComponentType::Craftables => 1300,
+            DestinyComponentType::SocialCommendations => 1400,
+            DestinyComponentType::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyComponentType {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyEnergyType {
+    Any,
+    Arc,
+    Thermal,
+    Void,
+    Ghost,
+    Subclass,
+    Stasis,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyEnergyType {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyEnergyType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyEnergyType::Any),
+            1 => Ok(DestinyEnergyType::Arc),
+            2 => Ok(DestinyEnergyType::Thermal),
+            3 => Ok(DestinyEnergyType::Void),
+            4 => Ok(DestinyEnergyType::Ghost),
+            5 => Ok(DestinyEnergyType::Subclass),
+            6 => Ok(DestinyEnergyType::Stasis),
+            _ => Ok(DestinyEnergyType::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyEnergyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyEnergyType> for i32 {
+    fn from(value: DestinyEnergyType) -> i32 {
+        match value {
+            DestinyEnergyType::Any => 0,
+            DestinyEnergyType::Arc => 1,
+            DestinyEnergyType::Thermal => 2,
+            DestinyEnergyType::Void => 3,
+            DestinyEnergyType::Ghost => 4,
+            DestinyEnergyType::Subclass => 5,
+            DestinyEnergyType::Stasis => 6,
+            DestinyEnergyType::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyEnergyType {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyGamePrivacySetting {
+    Open,
+    ClanAndFriendsOnly,
+    FriendsOnly,
+    InvitationOnly,
+    Closed,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyGamePrivacySetting {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyGamePrivacySetting, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyGamePrivacySetting::Open),
+            1 => Ok(DestinyGamePrivacySetting::ClanAndFriendsOnly),
+            2 => Ok(DestinyGamePrivacySetting::FriendsOnly),
+            3 => Ok(DestinyGamePrivacySetting::InvitationOnly),
+            4 => Ok(DestinyGamePrivacySetting::Closed),
+            _ => Ok(DestinyGamePrivacySetting::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyGamePrivacySetting {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyGamePrivacySetting> for i32 {
+    fn from(value: DestinyGamePrivacySetting) -> i32 {
+        match value {
+            DestinyGamePrivacySetting::Open => 0,
+            DestinyGamePrivacySetting::ClanAndFriendsOnly => 1,
+            DestinyGamePrivacySetting::FriendsOnly => 2,
+            DestinyGamePrivacySetting::InvitationOnly => 3,
+            DestinyGamePrivacySetting::Closed => 4,
+            DestinyGamePrivacySetting::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyGamePrivacySetting {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct DestinyGameVersions: u32 {
+        const Destiny2 = 1;
+        const DLC1 = 2;
+        const DLC2 = 4;
+        const Forsaken = 8;
+        const YearTwoAnnualPass = 16;
+        const Shadowkeep = 32;
+        const BeyondLight = 64;
+        const Anniversary30th = 128;
+        const TheWitchQueen = 256;
+        const Lightfall = 512;
+    }
+}
+
+impl<'de> Deserialize<'de> for DestinyGameVersions {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyGameVersions, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = u32::deserialize(deserializer)?;
+        Ok(DestinyGameVersions::from_bits_retain(s))
+    }
+}
+
+impl Serialize for DestinyGameVersions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.bits().serialize(serializer)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyGender {
+    Male,
+    Female,
+    Unknown,
+    /// A value not known to this crate. `Unknown` is a value Bungie defines.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyGender {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyGender, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyGender::Male),
+            1 => Ok(DestinyGender::Female),
+            2 => Ok(DestinyGender::Unknown),
+            _ => Ok(DestinyGender::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyGender {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyGender> for i32 {
+    fn from(value: DestinyGender) -> i32 {
+        match value {
+            DestinyGender::Male => 0,
+            DestinyGender::Female => 1,
+            DestinyGender::Unknown => 2,
+            DestinyGender::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyGender {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyItemSubType {
+    None,
+    Crucible,
+    Vanguard,
+    Exotic,
+    AutoRifle,
+    Shotgun,
+    Machinegun,
+    HandCannon,
+    RocketLauncher,
+    FusionRifle,
+    SniperRifle,
+    PulseRifle,
+    ScoutRifle,
+    Crm,
+    Sidearm,
+    Sword,
+    Mask,
+    Shader,
+    Ornament,
+    FusionRifleLine,
+    GrenadeLauncher,
+    SubmachineGun,
+    TraceRifle,
+    HelmetArmor,
+    GauntletsArmor,
+    ChestArmor,
+    LegArmor,
+    ClassArmor,
+    Bow,
+    DummyRepeatableBounty,
+    Glaive,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyItemSubType {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyItemSubType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyItemSubType::None),
+            1 => Ok(DestinyItemSubType::Crucible),
+            2 => Ok(DestinyItemSubType::Vanguard),
+            5 => Ok(DestinyItemSubType::Exotic),
+            6 => Ok(DestinyItemSubType::AutoRifle),
+            7 => Ok(DestinyItemSubType::Shotgun),
+            8 => Ok(DestinyItemSubType::Machinegun),
+            9 => Ok(DestinyItemSubType::HandCannon),
+            10 => Ok(DestinyItemSubType::RocketLauncher),
+            11 => Ok(DestinyItemSubType::FusionRifle),
+            12 => Ok(DestinyItemSubType::SniperRifle),
+            13 => Ok(DestinyItemSubType::PulseRifle),
+            14 => Ok(DestinyItemSubType::ScoutRifle),
+            16 => Ok(DestinyItemSubType::Crm),
+            17 => Ok(DestinyItemSubType::Sidearm),
+            18 => Ok(DestinyItemSubType::Sword),
+            19 => Ok(DestinyItemSubType::Mask),
+            20 => Ok(DestinyItemSubType::Shader),
+            21 => Ok(DestinyItemSubType::Ornament),
+            22 => Ok(DestinyItemSubType::FusionRifleLine),
+            23 => Ok(DestinyItemSubType::GrenadeLauncher),
+            24 => Ok(DestinyItemSubType::SubmachineGun),
+            25 => Ok(DestinyItemSubType::TraceRifle),
+            26 => Ok(DestinyItemSubType::HelmetArmor),
+            27 => Ok(DestinyItemSubType::GauntletsArmor),
+            28 => Ok(DestinyItemSubType::ChestArmor),
+            29 => Ok(DestinyItemSubType::LegArmor),
+            30 => Ok(DestinyItemSubType::ClassArmor),
+            31 => Ok(DestinyItemSubType::Bow),
+            32 => Ok(DestinyItemSubType::DummyRepeatableBounty),
+            33 => Ok(DestinyItemSubType::Glaive),
+            _ => Ok(DestinyItemSubType::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyItemSubType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyItemSubType> for i32 {
+    fn from(value: DestinyItemSubType) -> i32 {
+        match value {
+            DestinyItemSubType::None => 0,
+            DestinyItemSubType::Crucible => 1,
+            DestinyItemSubType::Vanguard => 2,
+            DestinyItemSubType::Exotic => 5,
+            DestinyItemSubType::AutoRifle => 6,
+            DestinyItemSubType::Shotgun => 7,
+            DestinyItemSubType::Machinegun => 8,
+            DestinyItemSubType::HandCannon => 9,
+            DestinyItemSubType::RocketLauncher => 10,
+            DestinyItemSubType::FusionRifle => 11,
+            DestinyItemSubType::SniperRifle => 12,
+            DestinyItemSubType::PulseRifle => 13,
+            DestinyItemSubType::ScoutRifle => 14,
+            DestinyItemSubType::Crm => 16,
+            DestinyItemSubType::Sidearm => 17,
+            DestinyItemSubType::Sword => 18,
+            DestinyItemSubType::Mask => 19,
+            DestinyItemSubType::Shader => 20,
+            DestinyItemSubType::Ornament => 21,
+            DestinyItemSubType::FusionRifleLine => 22,
+            DestinyItemSubType::GrenadeLauncher => 23,
+            DestinyItemSubType::SubmachineGun => 24,
+            DestinyItemSubType::TraceRifle => 25,
+            DestinyItemSubType::HelmetArmor => 26,
+            DestinyItemSubType::GauntletsArmor => 27,
+            DestinyItemSubType::ChestArmor => 28,
+            DestinyItemSubType::LegArmor => 29,
+            DestinyItemSubType::ClassArmor => 30,
+            DestinyItemSubType::Bow => 31,
+            DestinyItemSubType::DummyRepeatableBounty => 32,
+            DestinyItemSubType::Glaive => 33,
+            DestinyItemSubType::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyItemSubType {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyItemType {
+    None,
+    Currency,
+    Armor,
+    Weapon,
+    Message,
+    Engram,
+    Consumable,
+    ExchangeMaterial,
+    MissionReward,
+    QuestStep,
+    QuestStepComplete,
+    Emblem,
+    Quest,
+    Subclass,
+    ClanBanner,
+    Aura,
+    Mod,
+    Dummy,
+    Ship,
+    Vehicle,
+    Emote,
+    Ghost,
+    Package,
+    Bounty,
+    Wrapper,
+    SeasonalArtifact,
+    Finisher,
+    Pattern,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyItemType {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyItemType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyItemType::None),
+            1 => Ok(DestinyItemType::Currency),
+            2 => Ok(DestinyItemType::Armor),
+            3 => Ok(DestinyItemType::Weapon),
+            7 => Ok(DestinyItemType::Message),
+            8 => Ok(DestinyItemType::Engram),
+            9 => Ok(DestinyItemType::Consumable),
+            10 => Ok(DestinyItemType::ExchangeMaterial),
+            11 => Ok(DestinyItemType::MissionReward),
+            12 => Ok(DestinyItemType::QuestStep),
+            13 => Ok(DestinyItemType::QuestStepComplete),
+            14 => Ok(DestinyItemType::Emblem),
+            15 => Ok(DestinyItemType::Quest),
+            16 => Ok(DestinyItemType::Subclass),
+            17 => Ok(DestinyItemType::ClanBanner),
+            18 => Ok(DestinyItemType::Aura),
+            19 => Ok(DestinyItemType::Mod),
+            20 => Ok(DestinyItemType::Dummy),
+            21 => Ok(DestinyItemType::Ship),
+            22 => Ok(DestinyItemType::Vehicle),
+            23 => Ok(DestinyItemType::Emote),
+            24 => Ok(DestinyItemType::Ghost),
+            25 => Ok(DestinyItemType::Package),
+            26 => Ok(DestinyItemType::Bounty),
+            27 => Ok(DestinyItemType::Wrapper),
+            28 => Ok(DestinyItemType::SeasonalArtifact),
+            29 => Ok(DestinyItemType::Finisher),
+            30 => Ok(DestinyItemType::Pattern),
+            _ => Ok(DestinyItemType::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyItemType> for i32 {
+    fn from(value: DestinyItemType) -> i32 {
+        match value {
+            DestinyItemType::None => 0,
+            DestinyItemType::Currency => 1,
+            DestinyItemType::Armor => 2,
+            DestinyItemType::Weapon => 3,
+            DestinyItemType::Message => 7,
+            DestinyItemType::Engram => 8,
+            DestinyItemType::Consumable => 9,
+            DestinyItemType::ExchangeMaterial => 10,
+            DestinyItemType::MissionReward => 11,
+            DestinyItemType::QuestStep => 12,
+            DestinyItemType::QuestStepComplete => 13,
+            DestinyItemType::Emblem => 14,
+            DestinyItemType::Quest => 15,
+            DestinyItemType::Subclass => 16,
+            DestinyItemType::ClanBanner => 17,
+            DestinyItemType::Aura => 18,
+            DestinyItemType::Mod => 19,
+            DestinyItemType::Dummy => 20,
+            DestinyItemType::Ship => 21,
+            DestinyItemType::Vehicle => 22,
+            DestinyItemType::Emote => 23,
+            DestinyItemType::Ghost => 24,
+            DestinyItemType::Package => 25,
+            DestinyItemType::Bounty => 26,
+            DestinyItemType::Wrapper => 27,
+            DestinyItemType::SeasonalArtifact => 28,
+            DestinyItemType::Finisher => 29,
+            DestinyItemType::Pattern => 30,
+            DestinyItemType::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyItemType {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct DestinyJoinClosedReasons: u32 {
+        const InMatchmaking = 1;
+        const Loading = 2;
+        const SoloMode = 4;
+        const InternalReasons = 8;
+        const DisallowedByGameState = 16;
+        const Offline = 32768;
+    }
+}
+
+impl<'de> Deserialize<'de> for DestinyJoinClosedReasons {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyJoinClosedReasons, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = u32::deserialize(deserializer)?;
+        Ok(DestinyJoinClosedReasons::from_bits_retain(s))
+    }
+}
+
+impl Serialize for DestinyJoinClosedReasons {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.bits().serialize(serializer)
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct DestinyPartyMemberStates: u32 {
+        const FireteamMember = 1;
+        const PosseMember = 2;
+        const GroupMember = 4;
+        const PartyLeader = 8;
+    }
+}
+
+impl<'de> Deserialize<'de> for DestinyPartyMemberStates {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyPartyMemberStates, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = u32::deserialize(deserializer)?;
+        Ok(DestinyPartyMemberStates::from_bits_retain(s))
+    }
+}
+
+impl Serialize for DestinyPartyMemberStates {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.bits().serialize(serializer)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyPresentationDisplayStyle {
+    Category,
+    Badge,
+    Medals,
+    Collectible,
+    Record,
+    SeasonalTriumph,
+    GuardianRank,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyPresentationDisplayStyle {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyPresentationDisplayStyle, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyPresentationDisplayStyle::Category),
+            1 => Ok(DestinyPresentationDisplayStyle::Badge),
+            2 => Ok(DestinyPresentationDisplayStyle::Medals),
+            3 => Ok(DestinyPresentationDisplayStyle::Collectible),
+            4 => Ok(DestinyPresentationDisplayStyle::Record),
+            5 => Ok(DestinyPresentationDisplayStyle::SeasonalTriumph),
+            6 => Ok(DestinyPresentationDisplayStyle::GuardianRank),
+            _ => Ok(DestinyPresentationDisplayStyle::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyPresentationDisplayStyle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyPresentationDisplayStyle> for i32 {
+    fn from(value: DestinyPresentationDisplayStyle) -> i32 {
+        match value {
+            DestinyPresentationDisplayStyle::Category => 0,
+            DestinyPresentationDisplayStyle::Badge => 1,
+            DestinyPresentationDisplayStyle::Medals => 2,
+            DestinyPresentationDisplayStyle::Collectible => 3,
+            DestinyPresentationDisplayStyle::Record => 4,
+            DestinyPresentationDisplayStyle::SeasonalTriumph => 5,
+            DestinyPresentationDisplayStyle::GuardianRank => 6,
+            DestinyPresentationDisplayStyle::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyPresentationDisplayStyle {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct DestinyPresentationNodeState: u32 {
+        const Invisible = 1;
+        const Obscured = 2;
+    }
+}
+
+impl<'de> Deserialize<'de> for DestinyPresentationNodeState {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyPresentationNodeState, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = u32::deserialize(deserializer)?;
+        Ok(DestinyPresentationNodeState::from_bits_retain(s))
+    }
+}
+
+impl Serialize for DestinyPresentationNodeState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.bits().serialize(serializer)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyPresentationNodeType {
+    Default,
+    Category,
+    Collectibles,
+    Records,
+    Metric,
+    Craftable,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyPresentationNodeType {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyPresentationNodeType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyPresentationNodeType::Default),
+            1 => Ok(DestinyPresentationNodeType::Category),
+            2 => Ok(DestinyPresentationNodeType::Collectibles),
+            3 => Ok(DestinyPresentationNodeType::Records),
+            4 => Ok(DestinyPresentationNodeType::Metric),
+            5 => Ok(DestinyPresentationNodeType::Craftable),
+            _ => Ok(DestinyPresentationNodeType::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyPresentationNodeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyPresentationNodeType> for i32 {
+    fn from(value: DestinyPresentationNodeType) -> i32 {
+        match value {
+            DestinyPresentationNodeType::Default => 0,
+            DestinyPresentationNodeType::Category => 1,
+            DestinyPresentationNodeType::Collectibles => 2,
+            DestinyPresentationNodeType::Records => 3,
+            DestinyPresentationNodeType::Metric => 4,
+            DestinyPresentationNodeType::Craftable => 5,
+            DestinyPresentationNodeType::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyPresentationNodeType {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyPresentationScreenStyle {
+    Default,
+    CategorySets,
+    Badge,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyPresentationScreenStyle {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyPresentationScreenStyle, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyPresentationScreenStyle::Default),
+            1 => Ok(DestinyPresentationScreenStyle::CategorySets),
+            2 => Ok(DestinyPresentationScreenStyle::Badge),
+            _ => Ok(DestinyPresentationScreenStyle::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyPresentationScreenStyle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyPresentationScreenStyle> for i32 {
+    fn from(value: DestinyPresentationScreenStyle) -> i32 {
+        match value {
+            DestinyPresentationScreenStyle::Default => 0,
+            DestinyPresentationScreenStyle::CategorySets => 1,
+            DestinyPresentationScreenStyle::Badge => 2,
+            DestinyPresentationScreenStyle::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyPresentationScreenStyle {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyProgressionRewardItemAcquisitionBehavior {
+    Instant,
+    PlayerClaimRequired,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyProgressionRewardItemAcquisitionBehavior {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> Result<DestinyProgressionRewardItemAcquisitionBehavior, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyProgressionRewardItemAcquisitionBehavior::Instant),
+            1 => Ok(DestinyProgressionRewardItemAcquisitionBehavior::PlayerClaimRequired),
+            _ => Ok(DestinyProgressionRewardItemAcquisitionBehavior::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyProgressionRewardItemAcquisitionBehavior {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyProgressionRewardItemAcquisitionBehavior> for i32 {
+    fn from(value: DestinyProgressionRewardItemAcquisitionBehavior) -> i32 {
+        match value {
+            DestinyProgressionRewardItemAcquisitionBehavior::Instant => 0,
+            DestinyProgressionRewardItemAcquisitionBehavior::PlayerClaimRequired => 1,
+            DestinyProgressionRewardItemAcquisitionBehavior::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyProgressionRewardItemAcquisitionBehavior {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct DestinyProgressionRewardItemState: u32 {
+        const Invisible = 1;
+        const Earned = 2;
+        const Claimed = 4;
+        const ClaimAllowed = 8;
+    }
+}
+
+impl<'de> Deserialize<'de> for DestinyProgressionRewardItemState {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyProgressionRewardItemState, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = u32::deserialize(deserializer)?;
+        Ok(DestinyProgressionRewardItemState::from_bits_retain(s))
+    }
+}
+
+impl Serialize for DestinyProgressionRewardItemState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.bits().serialize(serializer)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyProgressionScope {
+    Account,
+    Character,
+    Clan,
+    Item,
+    ImplicitFromEquipment,
+    Mapped,
+    MappedAggregate,
+    MappedStat,
+    MappedUnlockValue,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyProgressionScope {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyProgressionScope, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyProgressionScope::Account),
+            1 => Ok(DestinyProgressionScope::Character),
+            2 => Ok(DestinyProgressionScope::Clan),
+            3 => Ok(DestinyProgressionScope::Item),
+            4 => Ok(DestinyProgressionScope::ImplicitFromEquipment),
+            5 => Ok(DestinyProgressionScope::Mapped),
+            6 => Ok(DestinyProgressionScope::MappedAggregate),
+            7 => Ok(DestinyProgressionScope::MappedStat),
+            8 => Ok(DestinyProgressionScope::MappedUnlockValue),
+            _ => Ok(DestinyProgressionScope::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyProgressionScope {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyProgressionScope> for i32 {
+    fn from(value: DestinyProgressionScope) -> i32 {
+        match value {
+            DestinyProgressionScope::Account => 0,
+            DestinyProgressionScope::Character => 1,
+            DestinyProgressionScope::Clan => 2,
+            DestinyProgressionScope::Item => 3,
+            DestinyProgressionScope::ImplicitFromEquipment => 4,
+            DestinyProgressionScope::Mapped => 5,
+            DestinyProgressionScope::MappedAggregate => 6,
+            DestinyProgressionScope::MappedStat => 7,
+            DestinyProgressionScope::MappedUnlockValue => 8,
+            DestinyProgressionScope::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyProgressionScope {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyProgressionStepDisplayEffect {
+    None,
+    Character,
+    Item,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyProgressionStepDisplayEffect {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyProgressionStepDisplayEffect, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyProgressionStepDisplayEffect::None),
+            1 => Ok(DestinyProgressionStepDisplayEffect::Character),
+            2 => Ok(DestinyProgressionStepDisplayEffect::Item),
+            _ => Ok(DestinyProgressionStepDisplayEffect::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyProgressionStepDisplayEffect {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyProgressionStepDisplayEffect> for i32 {
+    fn from(value: DestinyProgressionStepDisplayEffect) -> i32 {
+        match value {
+            DestinyProgressionStepDisplayEffect::None => 0,
+            DestinyProgressionStepDisplayEffect::Character => 1,
+            DestinyProgressionStepDisplayEffect::Item => 2,
+            DestinyProgressionStepDisplayEffect::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyProgressionStepDisplayEffect {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyRace {
+    Human,
+    Awoken,
+    Exo,
+    Unknown,
+    /// A value not known to this crate. `Unknown` is a value Bungie defines.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyRace {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyRace, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyRace::Human),
+            1 => Ok(DestinyRace::Awoken),
+            2 => Ok(DestinyRace::Exo),
+            3 => Ok(DestinyRace::Unknown),
+            _ => Ok(DestinyRace::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyRace {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyRace> for i32 {
+    fn from(value: DestinyRace) -> i32 {
+        match value {
+            DestinyRace::Human => 0,
+            DestinyRace::Awoken => 1,
+            DestinyRace::Exo => 2,
+            DestinyRace::Unknown => 3,
+            DestinyRace::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyRace {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct DestinyRecordState: u32 {
+        const RecordRedeemed = 1;
+        const RewardUnavailable = 2;
+        const ObjectiveNotCompleted = 4;
+        const Obscured = 8;
+        const Invisible = 16;
+        const EntitlementUnowned = 32;
+        const CanEquipTitle = 64;
+    }
+}
+
+impl<'de> Deserialize<'de> for DestinyRecordState {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyRecordState, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = u32::deserialize(deserializer)?;
+        Ok(DestinyRecordState::from_bits_retain(s))
+    }
+}
+
+impl Serialize for DestinyRecordState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.bits().serialize(serializer)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyRecordToastStyle {
+    None,
+    Record,
+    Lore,
+    Badge,
+    MetaRecord,
+    MedalComplete,
+    SeasonChallengeComplete,
+    GildedTitleComplete,
+    CraftingRecipeUnlocked,
+    ToastGuardianRankDetails,
+    PathfinderObjectiveCompleteRituals,
+    PathfinderObjectiveCompleteSchism,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyRecordToastStyle {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyRecordToastStyle, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyRecordToastStyle::None),
+            1 => Ok(DestinyRecordToastStyle::Record),
+            2 => Ok(DestinyRecordToastStyle::Lore),
+            3 => Ok(DestinyRecordToastStyle::Badge),
+            4 => Ok(DestinyRecordToastStyle::MetaRecord),
+            5 => Ok(DestinyRecordToastStyle::MedalComplete),
+            6 => Ok(DestinyRecordToastStyle::SeasonChallengeComplete),
+            7 => Ok(DestinyRecordToastStyle::GildedTitleComplete),
+            8 => Ok(DestinyRecordToastStyle::CraftingRecipeUnlocked),
+            9 => Ok(DestinyRecordToastStyle::ToastGuardianRankDetails),
+            10 => Ok(DestinyRecordToastStyle::PathfinderObjectiveCompleteRituals),
+            11 => Ok(DestinyRecordToastStyle::PathfinderObjectiveCompleteSchism),
+            _ => Ok(DestinyRecordToastStyle::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyRecordToastStyle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyRecordToastStyle> for i32 {
+    fn from(value: DestinyRecordToastStyle) -> i32 {
+        match value {
+            DestinyRecordToastStyle::None => 0,
+            DestinyRecordToastStyle::Record => 1,
+            DestinyRecordToastStyle::Lore => 2,
+            DestinyRecordToastStyle::Badge => 3,
+            DestinyRecordToastStyle::MetaRecord => 4,
+            DestinyRecordToastStyle::MedalComplete => 5,
+            DestinyRecordToastStyle::SeasonChallengeComplete => 6,
+            DestinyRecordToastStyle::GildedTitleComplete => 7,
+            DestinyRecordToastStyle::CraftingRecipeUnlocked => 8,
+            DestinyRecordToastStyle::ToastGuardianRankDetails => 9,
+            DestinyRecordToastStyle::PathfinderObjectiveCompleteRituals => 10,
+            DestinyRecordToastStyle::PathfinderObjectiveCompleteSchism => 11,
+            DestinyRecordToastStyle::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyRecordToastStyle {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyRecordValueStyle {
+    Integer,
+    Percentage,
+    Milliseconds,
+    Boolean,
+    Decimal,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyRecordValueStyle {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyRecordValueStyle, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyRecordValueStyle::Integer),
+            1 => Ok(DestinyRecordValueStyle::Percentage),
+            2 => Ok(DestinyRecordValueStyle::Milliseconds),
+            3 => Ok(DestinyRecordValueStyle::Boolean),
+            4 => Ok(DestinyRecordValueStyle::Decimal),
+            _ => Ok(DestinyRecordValueStyle::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyRecordValueStyle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyRecordValueStyle> for i32 {
+    fn from(value: DestinyRecordValueStyle) -> i32 {
+        match value {
+            DestinyRecordValueStyle::Integer => 0,
+            DestinyRecordValueStyle::Percentage => 1,
+            DestinyRecordValueStyle::Milliseconds => 2,
+            DestinyRecordValueStyle::Boolean => 3,
+            DestinyRecordValueStyle::Decimal => 4,
+            DestinyRecordValueStyle::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyRecordValueStyle {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyScope {
+    Profile,
+    Character,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyScope {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyScope, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyScope::Profile),
+            1 => Ok(DestinyScope::Character),
+            _ => Ok(DestinyScope::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyScope {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyScope> for i32 {
+    fn from(value: DestinyScope) -> i32 {
+        match value {
+            DestinyScope::Profile => 0,
+            DestinyScope::Character => 1,
+            DestinyScope::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyScope {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinySocketCategoryStyle {
+    Unknown,
+    Reusable,
+    Consumable,
+    Unlockable,
+    Intrinsic,
+    EnergyMeter,
+    LargePerk,
+    Abilities,
+    Supers,
+    /// A value not known to this crate. `Unknown` is a value Bungie defines.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinySocketCategoryStyle {
+    fn deserialize<D>(deserializer: D) -> Result<DestinySocketCategoryStyle, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinySocketCategoryStyle::Unknown),
+            1 => Ok(DestinySocketCategoryStyle::Reusable),
+            2 => Ok(DestinySocketCategoryStyle::Consumable),
+            3 => Ok(DestinySocketCategoryStyle::Unlockable),
+            4 => Ok(DestinySocketCategoryStyle::Intrinsic),
+            5 => Ok(DestinySocketCategoryStyle::EnergyMeter),
+            6 => Ok(DestinySocketCategoryStyle::LargePerk),
+            7 => Ok(DestinySocketCategoryStyle::Abilities),
+            8 => Ok(DestinySocketCategoryStyle::Supers),
+            _ => Ok(DestinySocketCategoryStyle::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinySocketCategoryStyle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinySocketCategoryStyle> for i32 {
+    fn from(value: DestinySocketCategoryStyle) -> i32 {
+        match value {
+            DestinySocketCategoryStyle::Unknown => 0,
+            DestinySocketCategoryStyle::Reusable => 1,
+            DestinySocketCategoryStyle::Consumable => 2,
+            DestinySocketCategoryStyle::Unlockable => 3,
+            DestinySocketCategoryStyle::Intrinsic => 4,
+            DestinySocketCategoryStyle::EnergyMeter => 5,
+            DestinySocketCategoryStyle::LargePerk => 6,
+            DestinySocketCategoryStyle::Abilities => 7,
+            DestinySocketCategoryStyle::Supers => 8,
+            DestinySocketCategoryStyle::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinySocketCategoryStyle {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinySocketVisibility {
+    Visible,
+    Hidden,
+    HiddenWhenEmpty,
+    HiddenIfNoPlugsAvailable,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinySocketVisibility {
+    fn deserialize<D>(deserializer: D) -> Result<DestinySocketVisibility, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinySocketVisibility::Visible),
+            1 => Ok(DestinySocketVisibility::Hidden),
+            2 => Ok(DestinySocketVisibility::HiddenWhenEmpty),
+            3 => Ok(DestinySocketVisibility::HiddenIfNoPlugsAvailable),
+            _ => Ok(DestinySocketVisibility::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinySocketVisibility {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinySocketVisibility> for i32 {
+    fn from(value: DestinySocketVisibility) -> i32 {
+        match value {
+            DestinySocketVisibility::Visible => 0,
+            DestinySocketVisibility::Hidden => 1,
+            DestinySocketVisibility::HiddenWhenEmpty => 2,
+            DestinySocketVisibility::HiddenIfNoPlugsAvailable => 3,
+            DestinySocketVisibility::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinySocketVisibility {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyStatAggregationType {
+    CharacterAverage,
+    Character,
+    Item,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyStatAggregationType {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyStatAggregationType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyStatAggregationType::CharacterAverage),
+            1 => Ok(DestinyStatAggregationType::Character),
+            2 => Ok(DestinyStatAggregationType::Item),
+            _ => Ok(DestinyStatAggregationType::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyStatAggregationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyStatAggregationType> for i32 {
+    fn from(value: DestinyStatAggregationType) -> i32 {
+        match value {
+            DestinyStatAggregationType::CharacterAverage => 0,
+            DestinyStatAggregationType::Character => 1,
+            DestinyStatAggregationType::Item => 2,
+            DestinyStatAggregationType::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyStatAggregationType {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyStatCategory {
+    Gameplay,
+    Weapon,
+    Defense,
+    Primary,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyStatCategory {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyStatCategory, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyStatCategory::Gameplay),
+            1 => Ok(DestinyStatCategory::Weapon),
+            2 => Ok(DestinyStatCategory::Defense),
+            3 => Ok(DestinyStatCategory::Primary),
+            _ => Ok(DestinyStatCategory::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyStatCategory {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyStatCategory> for i32 {
+    fn from(value: DestinyStatCategory) -> i32 {
+        match value {
+            DestinyStatCategory::Gameplay => 0,
+            DestinyStatCategory::Weapon => 1,
+            DestinyStatCategory::Defense => 2,
+            DestinyStatCategory::Primary => 3,
+            DestinyStatCategory::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyStatCategory {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyTalentNodeState {
+    Invalid,
+    CanUpgrade,
+    NoPoints,
+    NoPrerequisites,
+    NoSteps,
+    NoUnlock,
+    NoMaterial,
+    NoGridLevel,
+    SwappingLocked,
+    MustSwap,
+    Complete,
+    Unknown,
+    CreationOnly,
+    Hidden,
+    /// A value not known to this crate. `Unknown` is a value Bungie defines.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyTalentNodeState {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyTalentNodeState, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyTalentNodeState::Invalid),
+            1 => Ok(DestinyTalentNodeState::CanUpgrade),
+            2 => Ok(DestinyTalentNodeState::NoPoints),
+            3 => Ok(DestinyTalentNodeState::NoPrerequisites),
+            4 => Ok(DestinyTalentNodeState::NoSteps),
+            5 => Ok(DestinyTalentNodeState::NoUnlock),
+            6 => Ok(DestinyTalentNodeState::NoMaterial),
+            7 => Ok(DestinyTalentNodeState::NoGridLevel),
+            8 => Ok(DestinyTalentNodeState::SwappingLocked),
+            9 => Ok(DestinyTalentNodeState::MustSwap),
+            10 => Ok(DestinyTalentNodeState::Complete),
+            11 => Ok(DestinyTalentNodeState::Unknown),
+            12 => Ok(DestinyTalentNodeState::CreationOnly),
+            13 => Ok(DestinyTalentNodeState::Hidden),
+            _ => Ok(DestinyTalentNodeState::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyTalentNodeState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyTalentNodeState> for i32 {
+    fn from(value: DestinyTalentNodeState) -> i32 {
+        match value {
+            DestinyTalentNodeState::Invalid => 0,
+            DestinyTalentNodeState::CanUpgrade => 1,
+            DestinyTalentNodeState::NoPoints => 2,
+            DestinyTalentNodeState::NoPrerequisites => 3,
+            DestinyTalentNodeState::NoSteps => 4,
+            DestinyTalentNodeState::NoUnlock => 5,
+            DestinyTalentNodeState::NoMaterial => 6,
+            DestinyTalentNodeState::NoGridLevel => 7,
+            DestinyTalentNodeState::SwappingLocked => 8,
+            DestinyTalentNodeState::MustSwap => 9,
+            DestinyTalentNodeState::Complete => 10,
+            DestinyTalentNodeState::Unknown => 11,
+            DestinyTalentNodeState::CreationOnly => 12,
+            DestinyTalentNodeState::Hidden => 13,
+            DestinyTalentNodeState::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyTalentNodeState {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyVendorItemRefundPolicy {
+    NotRefundable,
+    DeletesItem,
+    RevokesLicense,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyVendorItemRefundPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyVendorItemRefundPolicy, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyVendorItemRefundPolicy::NotRefundable),
+            1 => Ok(DestinyVendorItemRefundPolicy::DeletesItem),
+            2 => Ok(DestinyVendorItemRefundPolicy::RevokesLicense),
+            _ => Ok(DestinyVendorItemRefundPolicy::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyVendorItemRefundPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyVendorItemRefundPolicy> for i32 {
+    fn from(value: DestinyVendorItemRefundPolicy) -> i32 {
+        match value {
+            DestinyVendorItemRefundPolicy::NotRefundable => 0,
+            DestinyVendorItemRefundPolicy::DeletesItem => 1,
+            DestinyVendorItemRefundPolicy::RevokesLicense => 2,
+            DestinyVendorItemRefundPolicy::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyVendorItemRefundPolicy {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct EquipFailureReason: u32 {
+        const ItemUnequippable = 1;
+        const ItemUniqueEquipRestricted = 2;
+        const ItemFailedUnlockCheck = 4;
+        const ItemFailedLevelCheck = 8;
+        const ItemWrapped = 16;
+        const ItemNotLoaded = 32;
+        const ItemEquipBlocklisted = 64;
+        const ItemLoadoutRequirementNotMet = 128;
+    }
+}
+
+impl<'de> Deserialize<'de> for EquipFailureReason {
+    fn deserialize<D>(deserializer: D) -> Result<EquipFailureReason, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = u32::deserialize(deserializer)?;
+        Ok(EquipFailureReason::from_bits_retain(s))
+    }
+}
+
+impl Serialize for EquipFailureReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.bits().serialize(serializer)
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct EquippingItemBlockAttributes: u32 {
+        const EquipOnAcquire = 1;
+    }
+}
+
+impl<'de> Deserialize<'de> for EquippingItemBlockAttributes {
+    fn deserialize<D>(deserializer: D) -> Result<EquippingItemBlockAttributes, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = u32::deserialize(deserializer)?;
+        Ok(EquippingItemBlockAttributes::from_bits_retain(s))
+    }
+}
+
+impl Serialize for EquippingItemBlockAttributes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.bits().serialize(serializer)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemBindStatus {
+    NotBound,
+    BoundToCharacter,
+    BoundToAccount,
+    BoundToGuild,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for ItemBindStatus {
+    fn deserialize<D>(deserializer: D) -> Result<ItemBindStatus, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(ItemBindStatus::NotBound),
+            1 => Ok(ItemBindStatus::BoundToCharacter),
+            2 => Ok(ItemBindStatus::BoundToAccount),
+            3 => Ok(ItemBindStatus::BoundToGuild),
+            _ => Ok(ItemBindStatus::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for ItemBindStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<ItemBindStatus> for i32 {
+    fn from(value: ItemBindStatus) -> i32 {
+        match value {
+            ItemBindStatus::NotBound => 0,
+            ItemBindStatus::BoundToCharacter => 1,
+            ItemBindStatus::BoundToAccount => 2,
+            ItemBindStatus::BoundToGuild => 3,
+            ItemBindStatus::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for ItemBindStatus {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemLocation {
+    Unknown,
+    Inventory,
+    Vault,
+    Vendor,
+    Postmaster,
+    /// A value not known to this crate. `Unknown` is a value Bungie defines.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for ItemLocation {
+    fn deserialize<D>(deserializer: D) -> Result<ItemLocation, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(ItemLocation::Unknown),
+            1 => Ok(ItemLocation::Inventory),
+            2 => Ok(ItemLocation::Vault),
+            3 => Ok(ItemLocation::Vendor),
+            4 => Ok(ItemLocation::Postmaster),
+            _ => Ok(ItemLocation::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for ItemLocation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<ItemLocation> for i32 {
+    fn from(value: ItemLocation) -> i32 {
+        match value {
+            ItemLocation::Unknown => 0,
+            ItemLocation::Inventory => 1,
+            ItemLocation::Vault => 2,
+            ItemLocation::Vendor => 3,
+            ItemLocation::Postmaster => 4,
+            ItemLocation::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for ItemLocation {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemPerkVisibility {
+    Visible,
+    Disabled,
+    Hidden,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for ItemPerkVisibility {
+    fn deserialize<D>(deserializer: D) -> Result<ItemPerkVisibility, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(ItemPerkVisibility::Visible),
+            1 => Ok(ItemPerkVisibility::Disabled),
+            2 => Ok(ItemPerkVisibility::Hidden),
+            _ => Ok(ItemPerkVisibility::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for ItemPerkVisibility {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<ItemPerkVisibility> for i32 {
+    fn from(value: ItemPerkVisibility) -> i32 {
+        match value {
+            ItemPerkVisibility::Visible => 0,
+            ItemPerkVisibility::Disabled => 1,
+            ItemPerkVisibility::Hidden => 2,
+            ItemPerkVisibility::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for ItemPerkVisibility {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct ItemState: u32 {
+        const Locked = 1;
+        const Tracked = 2;
+        const Masterwork = 4;
+        const Crafted = 8;
+        const HighlightedObjective = 16;
+    }
+}
+
+impl<'de> Deserialize<'de> for ItemState {
+    fn deserialize<D>(deserializer: D) -> Result<ItemState, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = u32::deserialize(deserializer)?;
+        Ok(ItemState::from_bits_retain(s))
+    }
+}
+
+impl Serialize for ItemState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.bits().serialize(serializer)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlugAvailabilityMode {
+    Normal,
+    UnavailableIfSocketContainsMatchingPlugCategory,
+    AvailableIfSocketContainsMatchingPlugCategory,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for PlugAvailabilityMode {
+    fn deserialize<D>(deserializer: D) -> Result<PlugAvailabilityMode, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(PlugAvailabilityMode::Normal),
+            1 => Ok(PlugAvailabilityMode::UnavailableIfSocketContainsMatchingPlugCategory),
+            2 => Ok(PlugAvailabilityMode::AvailableIfSocketContainsMatchingPlugCategory),
+            _ => Ok(PlugAvailabilityMode::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for PlugAvailabilityMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<PlugAvailabilityMode> for i32 {
+    fn from(value: PlugAvailabilityMode) -> i32 {
+        match value {
+            PlugAvailabilityMode::Normal => 0,
+            PlugAvailabilityMode::UnavailableIfSocketContainsMatchingPlugCategory => 1,
+            PlugAvailabilityMode::AvailableIfSocketContainsMatchingPlugCategory => 2,
+            PlugAvailabilityMode::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for PlugAvailabilityMode {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct PlugUiStyles: u32 {
+        const Masterwork = 1;
+    }
+}
+
+impl<'de> Deserialize<'de> for PlugUiStyles {
+    fn deserialize<D>(deserializer: D) -> Result<PlugUiStyles, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = u32::deserialize(deserializer)?;
+        Ok(PlugUiStyles::from_bits_retain(s))
+    }
+}
+
+impl Serialize for PlugUiStyles {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.bits().serialize(serializer)
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct SocketPlugSources: u32 {
+        const InventorySourced = 1;
+        const ReusablePlugItems = 2;
+        const ProfilePlugSet = 4;
+        const CharacterPlugSet = 8;
+    }
+}
+
+impl<'de> Deserialize<'de> for SocketPlugSources {
+    fn deserialize<D>(deserializer: D) -> Result<SocketPlugSources, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = u32::deserialize(deserializer)?;
+        Ok(SocketPlugSources::from_bits_retain(s))
+    }
+}
+
+impl Serialize for SocketPlugSources {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.bits().serialize(serializer)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SocketTypeActionType {
+    InsertPlug,
+    InfuseItem,
+    ReinitializeSocket,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for SocketTypeActionType {
+    fn deserialize<D>(deserializer: D) -> Result<SocketTypeActionType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(SocketTypeActionType::InsertPlug),
+            1 => Ok(SocketTypeActionType::InfuseItem),
+            2 => Ok(SocketTypeActionType::ReinitializeSocket),
+            _ => Ok(SocketTypeActionType::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for SocketTypeActionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<SocketTypeActionType> for i32 {
+    fn from(value: SocketTypeActionType) -> i32 {
+        match value {
+            SocketTypeActionType::InsertPlug => 0,
+            SocketTypeActionType::InfuseItem => 1,
+            SocketTypeActionType::ReinitializeSocket => 2,
+            SocketTypeActionType::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for SocketTypeActionType {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpecialItemType {
+    None,
+    SpecialCurrency,
+    Armor,
+    Weapon,
+    Engram,
+    Consumable,
+    ExchangeMaterial,
+    MissionReward,
+    Currency,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for SpecialItemType {
+    fn deserialize<D>(deserializer: D) -> Result<SpecialItemType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(SpecialItemType::None),
+            1 => Ok(SpecialItemType::SpecialCurrency),
+            8 => Ok(SpecialItemType::Armor),
+            9 => Ok(SpecialItemType::Weapon),
+            23 => Ok(SpecialItemType::Engram),
+            24 => Ok(SpecialItemType::Consumable),
+            25 => Ok(SpecialItemType::ExchangeMaterial),
+            27 => Ok(SpecialItemType::MissionReward),
+            29 => Ok(SpecialItemType::Currency),
+            _ => Ok(SpecialItemType::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for SpecialItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<SpecialItemType> for i32 {
+    fn from(value: SpecialItemType) -> i32 {
+        match value {
+            SpecialItemType::None => 0,
+            SpecialItemType::SpecialCurrency => 1,
+            SpecialItemType::Armor => 8,
+            SpecialItemType::Weapon => 9,
+            SpecialItemType::Engram => 23,
+            SpecialItemType::Consumable => 24,
+            SpecialItemType::ExchangeMaterial => 25,
+            SpecialItemType::MissionReward => 27,
+            SpecialItemType::Currency => 29,
+            SpecialItemType::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for SpecialItemType {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TierType {
+    Unknown,
+    Currency,
+    Basic,
+    Common,
+    Rare,
+    Superior,
+    Exotic,
+    /// A value not known to this crate. `Unknown` is a value Bungie defines.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for TierType {
+    fn deserialize<D>(deserializer: D) -> Result<TierType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(TierType::Unknown),
+            1 => Ok(TierType::Currency),
+            2 => Ok(TierType::Basic),
+            3 => Ok(TierType::Common),
+            4 => Ok(TierType::Rare),
+            5 => Ok(TierType::Superior),
+            6 => Ok(TierType::Exotic),
+            _ => Ok(TierType::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for TierType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<TierType> for i32 {
+    fn from(value: TierType) -> i32 {
+        match value {
+            TierType::Unknown => 0,
+            TierType::Currency => 1,
+            TierType::Basic => 2,
+            TierType::Common => 3,
+            TierType::Rare => 4,
+            TierType::Superior => 5,
+            TierType::Exotic => 6,
+            TierType::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for TierType {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct TransferStatuses: u32 {
+        const ItemIsEquipped = 1;
+        const NotTransferrable = 2;
+        const NoRoomInDestination = 4;
+    }
+}
+
+impl<'de> Deserialize<'de> for TransferStatuses {
+    fn deserialize<D>(deserializer: D) -> Result<TransferStatuses, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = u32::deserialize(deserializer)?;
+        Ok(TransferStatuses::from_bits_retain(s))
+    }
+}
+
+impl Serialize for TransferStatuses {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.bits().serialize(serializer)
+    }
+}
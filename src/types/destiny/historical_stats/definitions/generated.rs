@@ -0,0 +1,639 @@
+// @generated by `cargo xtask codegen`. Do not edit by hand.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyActivityModeType {
+    None,
+    Story,
+    Strike,
+    Raid,
+    AllPvP,
+    Patrol,
+    AllPvE,
+    Reserved9,
+    Control,
+    Reserved11,
+    Clash,
+    Reserved13,
+    CrimsonDoubles,
+    Nightfall,
+    HeroicNightfall,
+    AllStrikes,
+    IronBanner,
+    Reserved20,
+    Reserved21,
+    Reserved22,
+    Reserved24,
+    AllMayhem,
+    Reserved26,
+    Reserved27,
+    Reserved28,
+    Reserved29,
+    Reserved30,
+    Supremacy,
+    PrivateMatchesAll,
+    Survival,
+    Countdown,
+    TrialsOfTheNine,
+    Social,
+    TrialsCountdown,
+    TrialsSurvival,
+    IronBannerControl,
+    IronBannerClash,
+    IronBannerSupremacy,
+    ScoredNightfall,
+    ScoredHeroicNightfall,
+    Rumble,
+    AllDoubles,
+    Doubles,
+    PrivateMatchesClash,
+    PrivateMatchesControl,
+    PrivateMatchesSupremacy,
+    PrivateMatchesCountdown,
+    PrivateMatchesSurvival,
+    PrivateMatchesMayhem,
+    PrivateMatchesRumble,
+    HeroicAdventure,
+    Showdown,
+    Lockdown,
+    Scorched,
+    ScorchedTeam,
+    Gambit,
+    AllPvECompetitive,
+    Breakthrough,
+    BlackArmoryRun,
+    Salvage,
+    IronBannerSalvage,
+    PvPCompetitive,
+    PvPQuickplay,
+    ClashQuickplay,
+    ClashCompetitive,
+    ControlQuickplay,
+    ControlCompetitive,
+    GambitPrime,
+    Reckoning,
+    Menagerie,
+    VexOffensive,
+    NightmareHunt,
+    Elimination,
+    Momentum,
+    Dungeon,
+    Sundial,
+    TrialsOfOsiris,
+    Dares,
+    Offensive,
+    LostSector,
+    Rift,
+    ZoneControl,
+    IronBannerRift,
+    IronBannerZoneControl,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyActivityModeType {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyActivityModeType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyActivityModeType::None),
+            2 => Ok(DestinyActivityModeType::Story),
+            3 => Ok(DestinyActivityModeType::Strike),
+            4 => Ok(DestinyActivityModeType::Raid),
+            5 => Ok(DestinyActivityModeType::AllPvP),
+            6 => Ok(DestinyActivityModeType::Patrol),
+            7 => Ok(DestinyActivityModeType::AllPvE),
+            9 => Ok(DestinyActivityModeType::Reserved9),
+            10 => Ok(DestinyActivityModeType::Control),
+            11 => Ok(DestinyActivityModeType::Reserved11),
+            12 => Ok(DestinyActivityModeType::Clash),
+            13 => Ok(DestinyActivityModeType::Reserved13),
+            15 => Ok(DestinyActivityModeType::CrimsonDoubles),
+            16 => Ok(DestinyActivityModeType::Nightfall),
+            17 => Ok(DestinyActivityModeType::HeroicNightfall),
+            18 => Ok(DestinyActivityModeType::AllStrikes),
+            19 => Ok(DestinyActivityModeType::IronBanner),
+            20 => Ok(DestinyActivityModeType::Reserved20),
+            21 => Ok(DestinyActivityModeType::Reserved21),
+            22 => Ok(DestinyActivityModeType::Reserved22),
+            24 => Ok(DestinyActivityModeType::Reserved24),
+            25 => Ok(DestinyActivityModeType::AllMayhem),
+            26 => Ok(DestinyActivityModeType::Reserved26),
+            27 => Ok(DestinyActivityModeType::Reserved27),
+            28 => Ok(DestinyActivityModeType::Reserved28),
+            29 => Ok(DestinyActivityModeType::Reserved29),
+            30 => Ok(DestinyActivityModeType::Reserved30),
+            31 => Ok(DestinyActivityModeType::Supremacy),
+            32 => Ok(DestinyActivityModeType::PrivateMatchesAll),
+            37 => Ok(DestinyActivityModeType::Survival),
+            38 => Ok(DestinyActivityModeType::Countdown),
+            39 => Ok(DestinyActivityModeType::TrialsOfTheNine),
+            40 => Ok(DestinyActivityModeType::Social),
+            41 => Ok(DestinyActivityModeType::TrialsCountdown),
+            42 => Ok(DestinyActivityModeType::TrialsSurvival),
+            43 => Ok(DestinyActivityModeType::IronBannerControl),
+            44 => Ok(DestinyActivityModeType::IronBannerClash),
+            45 => Ok(DestinyActivityModeType::IronBannerSupremacy),
+            46 => Ok(DestinyActivityModeType::ScoredNightfall),
+            47 => Ok(DestinyActivityModeType::ScoredHeroicNightfall),
+            48 => Ok(DestinyActivityModeType::Rumble),
+            49 => Ok(DestinyActivityModeType::AllDoubles),
+            50 => Ok(DestinyActivityModeType::Doubles),
+            51 => Ok(DestinyActivityModeType::PrivateMatchesClash),
+            52 => Ok(DestinyActivityModeType::PrivateMatchesControl),
+            53 => Ok(DestinyActivityModeType::PrivateMatchesSupremacy),
+            54 => Ok(DestinyActivityModeType::PrivateMatchesCountdown),
+            55 => Ok(DestinyActivityModeType::PrivateMatchesSurvival),
+            56 => Ok(DestinyActivityModeType::PrivateMatchesMayhem),
+            57 => Ok(DestinyActivityModeType::PrivateMatchesRumble),
+            58 => Ok(DestinyActivityModeType::HeroicAdventure),
+            59 => Ok(DestinyActivityModeType::Showdown),
+            60 => Ok(DestinyActivityModeType::Lockdown),
+            61 => Ok(DestinyActivityModeType::Scorched),
+            62 => Ok(DestinyActivityModeType::ScorchedTeam),
+            63 => Ok(DestinyActivityModeType::Gambit),
+            64 => Ok(DestinyActivityModeType::AllPvECompetitive),
+            65 => Ok(DestinyActivityModeType::Breakthrough),
+            66 => Ok(DestinyActivityModeType::BlackArmoryRun),
+            67 => Ok(DestinyActivityModeType::Salvage),
+            68 => Ok(DestinyActivityModeType::IronBannerSalvage),
+            69 => Ok(DestinyActivityModeType::PvPCompetitive),
+            70 => Ok(DestinyActivityModeType::PvPQuickplay),
+            71 => Ok(DestinyActivityModeType::ClashQuickplay),
+            72 => Ok(DestinyActivityModeType::ClashCompetitive),
+            73 => Ok(DestinyActivityModeType::ControlQuickplay),
+            74 => Ok(DestinyActivityModeType::ControlCompetitive),
+            75 => Ok(DestinyActivityModeType::GambitPrime),
+            76 => Ok(DestinyActivityModeType::Reckoning),
+            77 => Ok(DestinyActivityModeType::Menagerie),
+            78 => Ok(DestinyActivityModeType::VexOffensive),
+            79 => Ok(DestinyActivityModeType::NightmareHunt),
+            80 => Ok(DestinyActivityModeType::Elimination),
+            81 => Ok(DestinyActivityModeType::Momentum),
+            82 => Ok(DestinyActivityModeType::Dungeon),
+            83 => Ok(DestinyActivityModeType::Sundial),
+            84 => Ok(DestinyActivityModeType::TrialsOfOsiris),
+            85 => Ok(DestinyActivityModeType::Dares),
+            86 => Ok(DestinyActivityModeType::Offensive),
+            87 => Ok(DestinyActivityModeType::LostSector),
+            88 => Ok(DestinyActivityModeType::Rift),
+            89 => Ok(DestinyActivityModeType::ZoneControl),
+            90 => Ok(DestinyActivityModeType::IronBannerRift),
+            91 => Ok(DestinyActivityModeType::IronBannerZoneControl),
+            _ => Ok(DestinyActivityModeType::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyActivityModeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyActivityModeType> for i32 {
+    fn from(value: DestinyActivityModeType) -> i32 {
+        match value {
+            DestinyActivityModeType::None => 0,
+            DestinyActivityModeType::Story => 2,
+            DestinyActivityModeType::Strike => 3,
+            DestinyActivityModeType::Raid => 4,
+            DestinyActivityModeType::AllPvP => 5,
+            DestinyActivityModeType::Patrol => 6,
+            DestinyActivityModeType::AllPvE => 7,
+            DestinyActivityModeType::Reserved9 => 9,
+            DestinyActivityModeType::Control => 10,
+            DestinyActivityModeType::Reserved11 => 11,
+            DestinyActivityModeType::Clash => 12,
+            DestinyActivityModeType::Reserved13 => 13,
+            DestinyActivityModeType::CrimsonDoubles => 15,
+            DestinyActivityModeType::Nightfall => 16,
+            DestinyActivityModeType::HeroicNightfall => 17,
+            DestinyActivityModeType::AllStrikes => 18,
+            DestinyActivityModeType::IronBanner => 19,
+            DestinyActivityModeType::Reserved20 => 20,
+            DestinyActivityModeType::Reserved21 => 21,
+            DestinyActivityModeType::Reserved22 => 22,
+            DestinyActivityModeType::Reserved24 => 24,
+            DestinyActivityModeType::AllMayhem => 25,
+            DestinyActivityModeType::Reserved26 => 26,
+            DestinyActivityModeType::Reserved27 => 27,
+            DestinyActivityModeType::Reserved28 => 28,
+            DestinyActivityModeType::Reserved29 => 29,
+            DestinyActivityModeType::Reserved30 => 30,
+            DestinyActivityModeType::Supremacy => 31,
+            DestinyActivityModeType::PrivateMatchesAll => 32,
+            DestinyActivityModeType::Survival => 37,
+            DestinyActivityModeType::Countdown => 38,
+            DestinyActivityModeType::TrialsOfTheNine => 39,
+            DestinyActivityModeType::Social => 40,
+            DestinyActivityModeType::TrialsCountdown => 41,
+            DestinyActivityModeType::TrialsSurvival => 42,
+            DestinyActivityModeType::IronBannerControl => 43,
+            DestinyActivityModeType::IronBannerClash => 44,
+            DestinyActivityModeType::IronBannerSupremacy => 45,
+            DestinyActivityModeType::ScoredNightfall => 46,
+            DestinyActivityModeType::ScoredHeroicNightfall => 47,
+            DestinyActivityModeType::Rumble => 48,
+            DestinyActivityModeType::AllDoubles => 49,
+            DestinyActivityModeType::Doubles => 50,
+            DestinyActivityModeType::PrivateMatchesClash => 51,
+            DestinyActivityModeType::PrivateMatchesControl => 52,
+            DestinyActivityModeType::PrivateMatchesSupremacy => 53,
+            DestinyActivityModeType::PrivateMatchesCountdown => 54,
+            DestinyActivityModeType::PrivateMatchesSurvival => 55,
+            DestinyActivityModeType::PrivateMatchesMayhem => 56,
+            DestinyActivityModeType::PrivateMatchesRumble => 57,
+            DestinyActivityModeType::HeroicAdventure => 58,
+            DestinyActivityModeType::Showdown => 59,
+            DestinyActivityModeType::Lockdown => 60,
+            DestinyActivityModeType::Scorched => 61,
+            DestinyActivityModeType::ScorchedTeam => 62,
+            DestinyActivityModeType::Gambit => 63,
+            DestinyActivityModeType::AllPvECompetitive => 64,
+            DestinyActivityModeType::Breakthrough => 65,
+            DestinyActivityModeType::BlackArmoryRun => 66,
+            DestinyActivityModeType::Salvage => 67,
+            DestinyActivityModeType::IronBannerSalvage => 68,
+            DestinyActivityModeType::PvPCompetitive => 69,
+            DestinyActivityModeType::PvPQuickplay => 70,
+            DestinyActivityModeType::ClashQuickplay => 71,
+            DestinyActivityModeType::ClashCompetitive => 72,
+            DestinyActivityModeType::ControlQuickplay => 73,
+            DestinyActivityModeType::ControlCompetitive => 74,
+            DestinyActivityModeType::GambitPrime => 75,
+            DestinyActivityModeType::Reckoning => 76,
+            DestinyActivityModeType::Menagerie => 77,
+            DestinyActivityModeType::VexOffensive => 78,
+            DestinyActivityModeType::NightmareHunt => 79,
+            DestinyActivityModeType::Elimination => 80,
+            DestinyActivityModeType::Momentum => 81,
+            DestinyActivityModeType::Dungeon => 82,
+            DestinyActivityModeType::Sundial => 83,
+            DestinyActivityModeType::TrialsOfOsiris => 84,
+            DestinyActivityModeType::Dares => 85,
+            DestinyActivityModeType::Offensive => 86,
+            DestinyActivityModeType::LostSector => 87,
+            DestinyActivityModeType::Rift => 88,
+            DestinyActivityModeType::ZoneControl => 89,
+            DestinyActivityModeType::IronBannerRift => 90,
+            DestinyActivityModeType::IronBannerZoneControl => 91,
+            DestinyActivityModeType::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyActivityModeType {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyStatsCategoryType {
+    None,
+    Kills,
+    Assists,
+    Deaths,
+    Criticals,
+    KDa,
+    KD,
+    Score,
+    Entered,
+    TimePlayed,
+    MedalWins,
+    MedalGame,
+    MedalSpecialKills,
+    MedalSprees,
+    MedalMultiKills,
+    MedalAbilities,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyStatsCategoryType {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyStatsCategoryType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyStatsCategoryType::None),
+            1 => Ok(DestinyStatsCategoryType::Kills),
+            2 => Ok(DestinyStatsCategoryType::Assists),
+            3 => Ok(DestinyStatsCategoryType::Deaths),
+            4 => Ok(DestinyStatsCategoryType::Criticals),
+            5 => Ok(DestinyStatsCategoryType::KDa),
+            6 => Ok(DestinyStatsCategoryType::KD),
+            7 => Ok(DestinyStatsCategoryType::Score),
+            8 => Ok(DestinyStatsCategoryType::Entered),
+            9 => Ok(DestinyStatsCategoryType::TimePlayed),
+            10 => Ok(DestinyStatsCategoryType::MedalWins),
+            11 => Ok(DestinyStatsCategoryType::MedalGame),
+            12 => Ok(DestinyStatsCategoryType::MedalSpecialKills),
+            13 => Ok(DestinyStatsCategoryType::MedalSprees),
+            14 => Ok(DestinyStatsCategoryType::MedalMultiKills),
+            15 => Ok(DestinyStatsCategoryType::MedalAbilities),
+            _ => Ok(DestinyStatsCategoryType::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyStatsCategoryType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyStatsCategoryType> for i32 {
+    fn from(value: DestinyStatsCategoryType) -> i32 {
+        match value {
+            DestinyStatsCategoryType::None => 0,
+            DestinyStatsCategoryType::Kills => 1,
+            DestinyStatsCategoryType::Assists => 2,
+            DestinyStatsCategoryType::Deaths => 3,
+            DestinyStatsCategoryType::Criticals => 4,
+            DestinyStatsCategoryType::KDa => 5,
+            DestinyStatsCategoryType::KD => 6,
+            DestinyStatsCategoryType::Score => 7,
+            DestinyStatsCategoryType::Entered => 8,
+            DestinyStatsCategoryType::TimePlayed => 9,
+            DestinyStatsCategoryType::MedalWins => 10,
+            DestinyStatsCategoryType::MedalGame => 11,
+            DestinyStatsCategoryType::MedalSpecialKills => 12,
+            DestinyStatsCategoryType::MedalSprees => 13,
+            DestinyStatsCategoryType::MedalMultiKills => 14,
+            DestinyStatsCategoryType::MedalAbilities => 15,
+            DestinyStatsCategoryType::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyStatsCategoryType {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyStatsGroupType {
+    None,
+    General,
+    Weapons,
+    Medals,
+    ReservedGroups,
+    Leaderboard,
+    Activity,
+    UniqueWeapon,
+    Internal,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyStatsGroupType {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyStatsGroupType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyStatsGroupType::None),
+            1 => Ok(DestinyStatsGroupType::General),
+            2 => Ok(DestinyStatsGroupType::Weapons),
+            3 => Ok(DestinyStatsGroupType::Medals),
+            100 => Ok(DestinyStatsGroupType::ReservedGroups),
+            101 => Ok(DestinyStatsGroupType::Leaderboard),
+            102 => Ok(DestinyStatsGroupType::Activity),
+            103 => Ok(DestinyStatsGroupType::UniqueWeapon),
+            104 => Ok(DestinyStatsGroupType::Internal),
+            _ => Ok(DestinyStatsGroupType::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyStatsGroupType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyStatsGroupType> for i32 {
+    fn from(value: DestinyStatsGroupType) -> i32 {
+        match value {
+            DestinyStatsGroupType::None => 0,
+            DestinyStatsGroupType::General => 1,
+            DestinyStatsGroupType::Weapons => 2,
+            DestinyStatsGroupType::Medals => 3,
+            DestinyStatsGroupType::ReservedGroups => 100,
+            DestinyStatsGroupType::Leaderboard => 101,
+            DestinyStatsGroupType::Activity => 102,
+            DestinyStatsGroupType::UniqueWeapon => 103,
+            DestinyStatsGroupType::Internal => 104,
+            DestinyStatsGroupType::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyStatsGroupType {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestinyStatsMergeMethods {
+    Add,
+    Min,
+    Max,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for DestinyStatsMergeMethods {
+    fn deserialize<D>(deserializer: D) -> Result<DestinyStatsMergeMethods, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(DestinyStatsMergeMethods::Add),
+            1 => Ok(DestinyStatsMergeMethods::Min),
+            2 => Ok(DestinyStatsMergeMethods::Max),
+            _ => Ok(DestinyStatsMergeMethods::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for DestinyStatsMergeMethods {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<DestinyStatsMergeMethods> for i32 {
+    fn from(value: DestinyStatsMergeMethods) -> i32 {
+        match value {
+            DestinyStatsMergeMethods::Add => 0,
+            DestinyStatsMergeMethods::Min => 1,
+            DestinyStatsMergeMethods::Max => 2,
+            DestinyStatsMergeMethods::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for DestinyStatsMergeMethods {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PeriodType {
+    None,
+    Daily,
+    AllTime,
+    Activity,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for PeriodType {
+    fn deserialize<D>(deserializer: D) -> Result<PeriodType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(PeriodType::None),
+            1 => Ok(PeriodType::Daily),
+            2 => Ok(PeriodType::AllTime),
+            3 => Ok(PeriodType::Activity),
+            _ => Ok(PeriodType::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for PeriodType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<PeriodType> for i32 {
+    fn from(value: PeriodType) -> i32 {
+        match value {
+            PeriodType::None => 0,
+            PeriodType::Daily => 1,
+            PeriodType::AllTime => 2,
+            PeriodType::Activity => 3,
+            PeriodType::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for PeriodType {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitType {
+    None,
+    Count,
+    PerGame,
+    Seconds,
+    Points,
+    Team,
+    Distance,
+    Percent,
+    Ratio,
+    Boolean,
+    WeaponType,
+    Standing,
+    Milliseconds,
+    CompletionReason,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for UnitType {
+    fn deserialize<D>(deserializer: D) -> Result<UnitType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(UnitType::None),
+            1 => Ok(UnitType::Count),
+            2 => Ok(UnitType::PerGame),
+            3 => Ok(UnitType::Seconds),
+            4 => Ok(UnitType::Points),
+            5 => Ok(UnitType::Team),
+            6 => Ok(UnitType::Distance),
+            7 => Ok(UnitType::Percent),
+            8 => Ok(UnitType::Ratio),
+            9 => Ok(UnitType::Boolean),
+            10 => Ok(UnitType::WeaponType),
+            11 => Ok(UnitType::Standing),
+            12 => Ok(UnitType::Milliseconds),
+            13 => Ok(UnitType::CompletionReason),
+            _ => Ok(UnitType::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for UnitType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<UnitType> for i32 {
+    fn from(value: UnitType) -> i32 {
+        match value {
+            UnitType::None => 0,
+            UnitType::Count => 1,
+            UnitType::PerGame => 2,
+            UnitType::Seconds => 3,
+            UnitType::Points => 4,
+            UnitType::Team => 5,
+            UnitType::Distance => 6,
+            UnitType::Percent => 7,
+            UnitType::Ratio => 8,
+            UnitType::Boolean => 9,
+            UnitType::WeaponType => 10,
+            UnitType::Standing => 11,
+            UnitType::Milliseconds => 12,
+            UnitType::CompletionReason => 13,
+            UnitType::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for UnitType {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
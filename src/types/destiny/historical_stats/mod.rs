@@ -1,18 +1,18 @@
 use std::collections::HashMap;
 
 use chrono::{DateTime, Utc};
-use definitions::DestinyActivityModeType;
+use definitions::{DestinyActivityModeType, HistoricalStatId};
 use serde::{Deserialize, Serialize};
 
-use crate::serde_as::string_to_u64;
 use crate::types::BungieMembershipType;
+use crate::types::ids::{ActivityInstanceId, CharacterId};
 use crate::types::user::UserInfoCard;
 
 pub mod definitions;
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyPostGameCarnageReportData {
     pub period: DateTime<Utc>,
     pub starting_phase_index: i32,
@@ -24,12 +24,11 @@ pub struct DestinyPostGameCarnageReportData {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyHistoricalStatsActivity {
     pub reference_id: u32,
     pub director_activity_hash: u32,
-    #[serde(deserialize_with = "string_to_u64")]
-    pub instance_id: u64,
+    pub instance_id: ActivityInstanceId,
     pub mode: DestinyActivityModeType,
     pub modes: Vec<DestinyActivityModeType>,
     pub is_private: bool,
@@ -38,30 +37,45 @@ pub struct DestinyHistoricalStatsActivity {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyPostGameCarnageReportEntry {
     pub standing: i32,
     pub score: DestinyHistoricalStatsValue,
     pub player: DestinyPlayer,
-    pub character_id: String,
+    pub character_id: CharacterId,
     pub values: HashMap<String, DestinyHistoricalStatsValue>,
     pub extended: Option<DestinyPostGameCarnageReportExtendedData>,
 }
 
+impl DestinyPostGameCarnageReportEntry {
+    pub fn stat(&self, id: HistoricalStatId) -> Option<&DestinyHistoricalStatsValue> {
+        self.values.get(id.as_str())
+    }
+
+    pub fn stat_value(&self, id: HistoricalStatId) -> Option<f64> {
+        self.stat(id).map(|v| v.basic.value)
+    }
+
+    /// Looks up a stat in the extended values, which hold medals and ability kills.
+    pub fn extended_stat(&self, id: HistoricalStatId) -> Option<&DestinyHistoricalStatsValue> {
+        self.extended.as_ref()?.values.get(id.as_str())
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyHistoricalStatsValue {
     pub stat_id: Option<String>,
     pub basic: DestinyHistoricalStatsValuePair,
     pub pga: Option<DestinyHistoricalStatsValuePair>,
     pub weighted: Option<DestinyHistoricalStatsValuePair>,
-    pub activity_id: Option<i64>,
+    pub activity_id: Option<ActivityInstanceId>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyHistoricalStatsValuePair {
     pub value: f64,
     pub display_value: String,
@@ -69,7 +83,7 @@ pub struct DestinyHistoricalStatsValuePair {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyPlayer {
     pub destiny_user_info: UserInfoCard,
     pub character_class: Option<String>,
@@ -93,7 +107,7 @@ pub struct DestinyPostGameCarnageReportExtendedData {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyHistoricalWeaponStats {
     pub reference_id: u32,
     pub values: HashMap<String, DestinyHistoricalStatsValue>,
@@ -101,7 +115,7 @@ pub struct DestinyHistoricalWeaponStats {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyPostGameCarnageReportTeamEntry {
     pub team_id: i32,
     pub standing: DestinyHistoricalStatsValue,
@@ -111,14 +125,145 @@ pub struct DestinyPostGameCarnageReportTeamEntry {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyHistoricalStatsPeriodGroup {
     pub period: DateTime<Utc>,
     pub activity_details: DestinyHistoricalStatsActivity,
     pub values: HashMap<String, DestinyHistoricalStatsValue>,
 }
 
+impl DestinyHistoricalStatsPeriodGroup {
+    pub fn stat(&self, id: HistoricalStatId) -> Option<&DestinyHistoricalStatsValue> {
+        self.values.get(id.as_str())
+    }
+
+    pub fn stat_value(&self, id: HistoricalStatId) -> Option<f64> {
+        self.stat(id).map(|v| v.basic.value)
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DestinyActivityHistoryResults {
+    #[serde(default)]
     pub activities: Vec<DestinyHistoricalStatsPeriodGroup>,
 }
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyHistoricalStatsAccountResult {
+    #[serde(default)]
+    pub merged_deleted_characters: DestinyHistoricalStatsWithMerged,
+    #[serde(default)]
+    pub merged_all_characters: DestinyHistoricalStatsWithMerged,
+    #[serde(default)]
+    pub characters: Vec<DestinyHistoricalStatsPerCharacter>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyHistoricalStatsWithMerged {
+    #[serde(default)]
+    pub results: HashMap<String, DestinyHistoricalStatsByPeriod>,
+    pub merged: Option<DestinyHistoricalStatsByPeriod>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyHistoricalStatsPerCharacter {
+    pub character_id: CharacterId,
+    pub deleted: bool,
+    #[serde(default)]
+    pub results: HashMap<String, DestinyHistoricalStatsByPeriod>,
+    pub merged: Option<DestinyHistoricalStatsByPeriod>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyHistoricalStatsByPeriod {
+    #[serde(default)]
+    pub all_time: HashMap<String, DestinyHistoricalStatsValue>,
+    #[serde(default)]
+    pub all_time_tier1: HashMap<String, DestinyHistoricalStatsValue>,
+    #[serde(default)]
+    pub all_time_tier2: HashMap<String, DestinyHistoricalStatsValue>,
+    #[serde(default)]
+    pub all_time_tier3: HashMap<String, DestinyHistoricalStatsValue>,
+    #[serde(default)]
+    pub daily: Vec<DestinyHistoricalStatsPeriodTotals>,
+    #[serde(default)]
+    pub monthly: Vec<DestinyHistoricalStatsPeriodTotals>,
+}
+
+/// A day or month of stats. Bungie documents these as `DestinyHistoricalStatsPeriodGroup`, but
+/// they cover many activities and so never carry `activityDetails`.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyHistoricalStatsPeriodTotals {
+    pub period: DateTime<Utc>,
+    pub values: HashMap<String, DestinyHistoricalStatsValue>,
+}
+
+impl DestinyHistoricalStatsPeriodTotals {
+    pub fn stat(&self, id: HistoricalStatId) -> Option<&DestinyHistoricalStatsValue> {
+        self.values.get(id.as_str())
+    }
+
+    pub fn stat_value(&self, id: HistoricalStatId) -> Option<f64> {
+        self.stat(id).map(|v| v.basic.value)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyAggregateActivityResults {
+    pub activities: Vec<DestinyAggregateActivityStats>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyAggregateActivityStats {
+    pub activity_hash: u32,
+    pub values: HashMap<String, DestinyHistoricalStatsValue>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyHistoricalWeaponStatsData {
+    #[serde(default)]
+    pub weapons: Vec<DestinyHistoricalWeaponStats>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyClanAggregateStat {
+    pub mode: DestinyActivityModeType,
+    pub stat_id: String,
+    pub value: DestinyHistoricalStatsValue,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyLeaderboard {
+    pub stat_id: String,
+    pub entries: Vec<DestinyLeaderboardEntry>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct DestinyLeaderboardEntry {
+    pub rank: i32,
+    pub player: DestinyPlayer,
+    pub character_id: CharacterId,
+    pub value: DestinyHistoricalStatsValue,
+}
@@ -7,14 +7,18 @@ use super::{challenges::DestinyChallengeStatus, quests::DestinyQuestStatus};
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyMilestone {
     pub milestone_hash: u32,
     pub available_quests: Option<Vec<DestinyMilestoneQuest>>,
+    #[serde(default)]
     pub activities: Vec<DestinyMilestoneChallengeActivity>,
+    #[serde(default)]
     pub values: HashMap<String, f32>,
     pub vendor_hashes: Option<Vec<u32>>,
+    #[serde(default)]
     pub vendors: Vec<DestinyMilestoneVendor>,
+    #[serde(default)]
     pub rewards: Vec<DestinyMilestoneRewardCategory>,
     pub start_date: DateTime<Utc>,
     pub end_date: DateTime<Utc>,
@@ -23,7 +27,7 @@ pub struct DestinyMilestone {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyMilestoneQuest {
     pub quest_item_hash: u32,
     pub status: DestinyQuestStatus,
@@ -33,7 +37,7 @@ pub struct DestinyMilestoneQuest {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyMilestoneActivity {
     pub activity_hash: u32,
     pub activity_mode_hash: u32,
@@ -44,7 +48,7 @@ pub struct DestinyMilestoneActivity {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyMilestoneActivityVariant {
     pub activity_hash: u32,
     pub completion_status: DestinyMilestoneActivityCompletionStatus,
@@ -66,7 +70,7 @@ pub struct DestinyMilestoneActivityPhase {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyMilestoneChallengeActivity {
     pub activity_hash: u32,
     pub challenges: Vec<DestinyChallengeStatus>,
@@ -78,7 +82,7 @@ pub struct DestinyMilestoneChallengeActivity {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyMilestoneVendor {
     pub vendor_hash: u32,
     pub preview_item_hash: u32,
@@ -86,7 +90,7 @@ pub struct DestinyMilestoneVendor {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyMilestoneRewardCategory {
     pub reward_category_hash: u32,
     pub entries: Vec<DestinyMilestoneRewardEntry>,
@@ -94,7 +98,7 @@ pub struct DestinyMilestoneRewardCategory {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyMilestoneRewardEntry {
     pub reward_entry_hash: u32,
     pub earned: bool,
@@ -103,17 +107,18 @@ pub struct DestinyMilestoneRewardEntry {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyMilestoneContent {
     pub about: Option<String>,
     pub status: Option<String>,
     pub tips: Option<Vec<String>>,
+    #[serde(default)]
     pub item_categories: Vec<DestinyMilestoneContentItemCategory>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyMilestoneContentItemCategory {
     pub title: Option<String>,
     pub item_hashes: Option<Vec<u32>>,
@@ -121,50 +126,57 @@ pub struct DestinyMilestoneContentItemCategory {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyPublicMilestone {
     pub milestone_hash: u32,
+    #[serde(default)]
     pub available_quests: Vec<DestinyPublicMilestoneQuest>,
+    #[serde(default)]
     pub activities: Vec<DestinyPublicMilestoneChallengeActivity>,
+    #[serde(default)]
     pub vendor_hashes: Vec<u32>,
+    #[serde(default)]
     pub vendors: Vec<DestinyPublicMilestoneVendor>,
-    pub start_date: DateTime<Utc>,
-    pub end_date: DateTime<Utc>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
     pub order: i32,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyPublicMilestoneQuest {
     pub quest_item_hash: u32,
-    pub activity: DestinyPublicMilestoneActivity,
+    pub activity: Option<DestinyPublicMilestoneActivity>,
+    #[serde(default)]
     pub challenges: Vec<DestinyPublicMilestoneChallenge>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyPublicMilestoneActivity {
     pub activity_hash: u32,
+    #[serde(default)]
     pub modifier_hashes: Vec<u32>,
+    #[serde(default)]
     pub variants: Vec<DestinyPublicMilestoneActivityVariant>,
-    pub activity_mode_hash: u32,
-    pub activity_mode_type: i32,
+    pub activity_mode_hash: Option<u32>,
+    pub activity_mode_type: Option<i32>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyPublicMilestoneActivityVariant {
     pub activity_hash: u32,
-    pub activity_mode_hash: u32,
-    pub activity_mode_type: i32,
+    pub activity_mode_hash: Option<u32>,
+    pub activity_mode_type: Option<i32>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyPublicMilestoneChallenge {
     pub objective_hash: u32,
     pub activity_hash: u32,
@@ -172,20 +184,24 @@ pub struct DestinyPublicMilestoneChallenge {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyPublicMilestoneChallengeActivity {
     pub activity_hash: u32,
+    #[serde(default)]
     pub challenge_objective_hashes: Vec<u32>,
+    #[serde(default)]
     pub modifier_hashes: Vec<u32>,
-    pub loadout_requirement_index: i32,
+    pub loadout_requirement_index: Option<i32>,
+    #[serde(default)]
     pub phase_hashes: Vec<u32>,
+    #[serde(default)]
     pub boolean_activity_options: HashMap<u32, bool>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyPublicMilestoneVendor {
     pub vendor_hash: u32,
-    pub preview_item_hash: u32,
+    pub preview_item_hash: Option<u32>,
 }
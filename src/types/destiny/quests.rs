@@ -1,8 +1,9 @@
+use crate::types::ids::ItemInstanceId;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyObjectiveProgress {
     pub objective_hash: u32,
     pub destination_hash: u32,
@@ -15,13 +16,13 @@ pub struct DestinyObjectiveProgress {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyQuestStatus {
     pub quest_hash: u32,
     pub step_hash: u32,
     pub step_objectives: Option<Vec<DestinyObjectiveProgress>>,
     pub tracked: bool,
-    pub item_instance_id: i64,
+    pub item_instance_id: ItemInstanceId,
     pub completed: bool,
     pub redeemed: bool,
     pub started: bool,
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::types::components::ComponentResponse;
+use crate::types::components::{ComponentResponse, ComponentUnavailable};
 use crate::types::{DestinyBaseItemComponentSetOfuint32, DestinyItemComponentSetOfint64};
 
 use super::components::collectibles::{
@@ -30,10 +30,11 @@ use super::entities::characters::{
 };
 use super::entities::inventory::DestinyInventoryComponent;
 use super::entities::profiles::{DestinyProfileComponent, DestinyVendorReceiptsComponent};
+use crate::types::ids::CharacterId;
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyProfileResponse {
     pub response_minted_timestamp: DateTime<Utc>,
     pub secondary_components_minted_timestamp: DateTime<Utc>,
@@ -52,29 +53,83 @@ pub struct DestinyProfileResponse {
     pub metrics: Option<ComponentResponse<DestinyMetricsComponent>>,
     pub profile_string_variables: Option<ComponentResponse<DestinyStringVariablesComponent>>,
     pub profile_commendations: Option<ComponentResponse<DestinySocialCommendationsComponent>>,
-    pub characters: Option<ComponentResponse<HashMap<i64, DestinyCharacterComponent>>>,
-    pub character_inventories: Option<ComponentResponse<HashMap<i64, DestinyInventoryComponent>>>,
-    pub character_loadouts: Option<ComponentResponse<HashMap<i64, DestinyLoadoutsComponent>>>,
+    pub characters: Option<ComponentResponse<HashMap<CharacterId, DestinyCharacterComponent>>>,
+    pub character_inventories:
+        Option<ComponentResponse<HashMap<CharacterId, DestinyInventoryComponent>>>,
+    pub character_loadouts:
+        Option<ComponentResponse<HashMap<CharacterId, DestinyLoadoutsComponent>>>,
     pub character_progressions:
-        Option<ComponentResponse<HashMap<i64, DestinyCharacterProgressionComponent>>>,
+        Option<ComponentResponse<HashMap<CharacterId, DestinyCharacterProgressionComponent>>>,
     pub character_render_data:
-        Option<ComponentResponse<HashMap<i64, DestinyCharacterRenderComponent>>>,
+        Option<ComponentResponse<HashMap<CharacterId, DestinyCharacterRenderComponent>>>,
     pub character_activities:
-        Option<ComponentResponse<HashMap<i64, DestinyCharacterActivitiesComponent>>>,
-    pub character_equipment: Option<ComponentResponse<HashMap<i64, DestinyInventoryComponent>>>,
-    pub character_kiosks: Option<ComponentResponse<HashMap<i64, DestinyKiosksComponent>>>,
-    pub character_plug_sets: Option<ComponentResponse<HashMap<i64, DestinyPlugSetsComponent>>>,
+        Option<ComponentResponse<HashMap<CharacterId, DestinyCharacterActivitiesComponent>>>,
+    pub character_equipment:
+        Option<ComponentResponse<HashMap<CharacterId, DestinyInventoryComponent>>>,
+    pub character_kiosks: Option<ComponentResponse<HashMap<CharacterId, DestinyKiosksComponent>>>,
+    pub character_plug_sets:
+        Option<ComponentResponse<HashMap<CharacterId, DestinyPlugSetsComponent>>>,
     pub character_uninstanced_item_components: Option<DestinyBaseItemComponentSetOfuint32>,
     pub character_presentation_nodes:
-        Option<ComponentResponse<HashMap<i64, DestinyPresentationNodesComponent>>>,
+        Option<ComponentResponse<HashMap<CharacterId, DestinyPresentationNodesComponent>>>,
     pub character_records:
-        Option<ComponentResponse<HashMap<i64, DestinyCharacterRecordsComponent>>>,
+        Option<ComponentResponse<HashMap<CharacterId, DestinyCharacterRecordsComponent>>>,
     pub character_collectibles:
-        Option<ComponentResponse<HashMap<i64, DestinyCollectiblesComponent>>>,
+        Option<ComponentResponse<HashMap<CharacterId, DestinyCollectiblesComponent>>>,
     pub character_string_variables:
-        Option<ComponentResponse<HashMap<i64, DestinyStringVariablesComponent>>>,
-    pub character_craftables: Option<ComponentResponse<HashMap<i64, DestinyCraftablesComponent>>>,
+        Option<ComponentResponse<HashMap<CharacterId, DestinyStringVariablesComponent>>>,
+    pub character_craftables:
+        Option<ComponentResponse<HashMap<CharacterId, DestinyCraftablesComponent>>>,
     pub item_components: Option<DestinyItemComponentSetOfint64>,
     pub character_currency_lookups:
-        Option<ComponentResponse<HashMap<i64, DestinyCurrenciesComponent>>>,
+        Option<ComponentResponse<HashMap<CharacterId, DestinyCurrenciesComponent>>>,
+}
+
+macro_rules! component_accessors {
+    ($($name:ident: $data:ty,)*) => {
+        $(
+            pub fn $name(&self) -> Result<&$data, ComponentUnavailable> {
+                self.$name
+                    .as_ref()
+                    .ok_or(ComponentUnavailable::NotRequested)?
+                    .data()
+            }
+        )*
+    };
+}
+
+/// Each accessor returns the component's data, or why it is unavailable.
+impl DestinyProfileResponse {
+    component_accessors! {
+        vendor_receipts: DestinyVendorReceiptsComponent,
+        profile_inventory: DestinyInventoryComponent,
+        profile_currencies: DestinyInventoryComponent,
+        profile: DestinyProfileComponent,
+        platform_silver: DestinyPlatformSilverComponent,
+        profile_kiosks: DestinyKiosksComponent,
+        profile_plug_sets: DestinyPlugSetsComponent,
+        profile_progression: DestinyProfileProgressionComponent,
+        profile_presentation_nodes: DestinyPresentationNodesComponent,
+        profile_records: DestinyProfileRecordsComponent,
+        profile_collectibles: DestinyProfileCollectiblesComponent,
+        profile_transitory_data: DestinyProfileTransitoryComponent,
+        metrics: DestinyMetricsComponent,
+        profile_string_variables: DestinyStringVariablesComponent,
+        profile_commendations: DestinySocialCommendationsComponent,
+        characters: HashMap<CharacterId, DestinyCharacterComponent>,
+        character_inventories: HashMap<CharacterId, DestinyInventoryComponent>,
+        character_loadouts: HashMap<CharacterId, DestinyLoadoutsComponent>,
+        character_progressions: HashMap<CharacterId, DestinyCharacterProgressionComponent>,
+        character_render_data: HashMap<CharacterId, DestinyCharacterRenderComponent>,
+        character_activities: HashMap<CharacterId, DestinyCharacterActivitiesComponent>,
+        character_equipment: HashMap<CharacterId, DestinyInventoryComponent>,
+        character_kiosks: HashMap<CharacterId, DestinyKiosksComponent>,
+        character_plug_sets: HashMap<CharacterId, DestinyPlugSetsComponent>,
+        character_presentation_nodes: HashMap<CharacterId, DestinyPresentationNodesComponent>,
+        character_records: HashMap<CharacterId, DestinyCharacterRecordsComponent>,
+        character_collectibles: HashMap<CharacterId, DestinyCollectiblesComponent>,
+        character_string_variables: HashMap<CharacterId, DestinyStringVariablesComponent>,
+        character_craftables: HashMap<CharacterId, DestinyCraftablesComponent>,
+        character_currency_lookups: HashMap<CharacterId, DestinyCurrenciesComponent>,
+    }
 }
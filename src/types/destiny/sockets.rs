@@ -4,7 +4,7 @@ use super::quests::DestinyObjectiveProgress;
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemPlugBase {
     pub plug_item_hash: u32,
     pub can_insert: bool,
@@ -15,7 +15,7 @@ pub struct DestinyItemPlugBase {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemPlug {
     pub plug_objectives: Vec<DestinyObjectiveProgress>,
     pub plug_item_hash: u32,
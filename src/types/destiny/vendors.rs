@@ -2,15 +2,16 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use super::{DestinyItemQuantity, DestinyVendorItemRefundPolicy};
+use crate::types::ids::CharacterId;
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyVendorReceipt {
     pub currency_paid: Vec<DestinyItemQuantity>,
     pub item_received: DestinyItemQuantity,
     pub license_unlock_hash: u32,
-    pub purchased_by_character_id: i64,
+    pub purchased_by_character_id: CharacterId,
     pub refund_policy: DestinyVendorItemRefundPolicy,
     pub sequence_number: i32,
     pub time_to_expiration: i64,
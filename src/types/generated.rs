@@ -0,0 +1,203 @@
+// @generated by `cargo xtask codegen`. Do not edit by hand.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BungieMembershipType {
+    None,
+    TigerXbox,
+    TigerPsn,
+    TigerSteam,
+    TigerBlizzard,
+    TigerStadia,
+    TigerEgs,
+    TigerDemon,
+    BungieNext,
+    All,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for BungieMembershipType {
+    fn deserialize<D>(deserializer: D) -> Result<BungieMembershipType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(BungieMembershipType::None),
+            1 => Ok(BungieMembershipType::TigerXbox),
+            2 => Ok(BungieMembershipType::TigerPsn),
+            3 => Ok(BungieMembershipType::TigerSteam),
+            4 => Ok(BungieMembershipType::TigerBlizzard),
+            5 => Ok(BungieMembershipType::TigerStadia),
+            6 => Ok(BungieMembershipType::TigerEgs),
+            10 => Ok(BungieMembershipType::TigerDemon),
+            254 => Ok(BungieMembershipType::BungieNext),
+            -1 => Ok(BungieMembershipType::All),
+            _ => Ok(BungieMembershipType::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for BungieMembershipType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<BungieMembershipType> for i32 {
+    fn from(value: BungieMembershipType) -> i32 {
+        match value {
+            BungieMembershipType::None => 0,
+            BungieMembershipType::TigerXbox => 1,
+            BungieMembershipType::TigerPsn => 2,
+            BungieMembershipType::TigerSteam => 3,
+            BungieMembershipType::TigerBlizzard => 4,
+            BungieMembershipType::TigerStadia => 5,
+            BungieMembershipType::TigerEgs => 6,
+            BungieMembershipType::TigerDemon => 10,
+            BungieMembershipType::BungieNext => 254,
+            BungieMembershipType::All => -1,
+            BungieMembershipType::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for BungieMembershipType {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct GlobalAlert {
+    #[serde(rename = "AlertHtml")]
+    pub alert_html: String,
+    #[serde(rename = "AlertKey")]
+    pub alert_key: String,
+    #[serde(rename = "AlertLevel")]
+    pub alert_level: GlobalAlertLevel,
+    #[serde(rename = "AlertLink")]
+    pub alert_link: String,
+    #[serde(rename = "AlertTimestamp")]
+    pub alert_timestamp: DateTime<Utc>,
+    #[serde(rename = "AlertType")]
+    pub alert_type: GlobalAlertType,
+    #[serde(rename = "StreamInfo")]
+    pub stream_info: StreamInfo,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GlobalAlertLevel {
+    Unknown,
+    Blue,
+    Yellow,
+    Red,
+    /// A value not known to this crate. `Unknown` is a value Bungie defines.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for GlobalAlertLevel {
+    fn deserialize<D>(deserializer: D) -> Result<GlobalAlertLevel, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(GlobalAlertLevel::Unknown),
+            1 => Ok(GlobalAlertLevel::Blue),
+            2 => Ok(GlobalAlertLevel::Yellow),
+            3 => Ok(GlobalAlertLevel::Red),
+            _ => Ok(GlobalAlertLevel::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for GlobalAlertLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<GlobalAlertLevel> for i32 {
+    fn from(value: GlobalAlertLevel) -> i32 {
+        match value {
+            GlobalAlertLevel::Unknown => 0,
+            GlobalAlertLevel::Blue => 1,
+            GlobalAlertLevel::Yellow => 2,
+            GlobalAlertLevel::Red => 3,
+            GlobalAlertLevel::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for GlobalAlertLevel {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GlobalAlertType {
+    GlobalAlert,
+    StreamingAlert,
+    /// A value not known to this crate.
+    Unrecognized(i32),
+}
+
+impl<'de> Deserialize<'de> for GlobalAlertType {
+    fn deserialize<D>(deserializer: D) -> Result<GlobalAlertType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = i32::deserialize(deserializer)?;
+        match s {
+            0 => Ok(GlobalAlertType::GlobalAlert),
+            1 => Ok(GlobalAlertType::StreamingAlert),
+            _ => Ok(GlobalAlertType::Unrecognized(s)),
+        }
+    }
+}
+
+impl Serialize for GlobalAlertType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i32::from(*self).serialize(serializer)
+    }
+}
+
+impl From<GlobalAlertType> for i32 {
+    fn from(value: GlobalAlertType) -> i32 {
+        match value {
+            GlobalAlertType::GlobalAlert => 0,
+            GlobalAlertType::StreamingAlert => 1,
+            GlobalAlertType::Unrecognized(value) => value,
+        }
+    }
+}
+
+impl crate::types::FallbackEnum for GlobalAlertType {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unrecognized(_))
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
+pub struct StreamInfo {
+    #[serde(rename = "ChannelName")]
+    pub channel_name: String,
+}
@@ -12,65 +12,27 @@ use destiny::{
         DestinyItemTalentGridComponent,
     },
 };
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use ids::ItemInstanceId;
+use serde::{Deserialize, Serialize};
 
 pub mod common;
 pub mod components;
 pub mod definitions;
 pub mod destiny;
 pub mod exceptions;
+mod generated;
+pub mod ids;
 pub mod links;
 pub mod misc;
 pub mod response;
 pub mod user;
+pub use destiny::{ItemLocation, TierType};
+pub use generated::*;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[repr(i16)]
-pub enum BungieMembershipType {
-    None = 0,
-    TigerXbox = 1,
-    TigerPsn = 2,
-    TigerSteam = 3,
-    TigerBlizzard = 4,
-    TigerStadia = 5,
-    TigerEgs = 6,
-    TigerDemon = 10,
-    BungieNext = 254,
-    All = -1,
-}
-
-impl<'de> Deserialize<'de> for BungieMembershipType {
-    fn deserialize<D>(deserializer: D) -> Result<BungieMembershipType, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let s = i32::deserialize(deserializer)?;
-        match s {
-            0 => Ok(BungieMembershipType::None),
-            1 => Ok(BungieMembershipType::TigerXbox),
-            2 => Ok(BungieMembershipType::TigerPsn),
-            3 => Ok(BungieMembershipType::TigerSteam),
-            4 => Ok(BungieMembershipType::TigerBlizzard),
-            5 => Ok(BungieMembershipType::TigerStadia),
-            6 => Ok(BungieMembershipType::TigerEgs),
-            10 => Ok(BungieMembershipType::TigerDemon),
-            254 => Ok(BungieMembershipType::BungieNext),
-            -1 => Ok(BungieMembershipType::All),
-            _ => Err(serde::de::Error::custom(format!(
-                "unknown BungieMembershipType: {s}"
-            ))),
-        }
-    }
-}
-
-impl Serialize for BungieMembershipType {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        let s = *self as i16;
-        s.serialize(serializer)
-    }
+/// Integer enums that keep values this crate does not know in an `Unrecognized` variant instead
+/// of failing to deserialize.
+pub trait FallbackEnum {
+    fn is_unrecognized(&self) -> bool;
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -81,109 +43,124 @@ pub struct DestinyBaseItemComponentSetOfuint32 {
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct DestinyItemComponentSetOfint64 {
-    pub instances: ComponentResponse<HashMap<i64, DestinyItemInstanceComponent>>,
-    pub render_data: ComponentResponse<HashMap<i64, DestinyItemRenderComponent>>,
-    pub stats: ComponentResponse<HashMap<i64, DestinyItemStatsComponent>>,
-    pub sockets: ComponentResponse<HashMap<i64, DestinyItemSocketsComponent>>,
-    pub reusable_plugs: ComponentResponse<HashMap<i64, DestinyItemReusablePlugsComponent>>,
-    pub plug_objectives: ComponentResponse<HashMap<i64, DestinyItemPlugObjectivesComponent>>,
-    pub talent_grids: ComponentResponse<HashMap<i64, DestinyItemTalentGridComponent>>,
+    pub instances: ComponentResponse<HashMap<ItemInstanceId, DestinyItemInstanceComponent>>,
+    pub render_data: ComponentResponse<HashMap<ItemInstanceId, DestinyItemRenderComponent>>,
+    pub stats: ComponentResponse<HashMap<ItemInstanceId, DestinyItemStatsComponent>>,
+    pub sockets: ComponentResponse<HashMap<ItemInstanceId, DestinyItemSocketsComponent>>,
+    pub reusable_plugs:
+        ComponentResponse<HashMap<ItemInstanceId, DestinyItemReusablePlugsComponent>>,
+    pub plug_objectives:
+        ComponentResponse<HashMap<ItemInstanceId, DestinyItemPlugObjectivesComponent>>,
+    pub talent_grids: ComponentResponse<HashMap<ItemInstanceId, DestinyItemTalentGridComponent>>,
     pub plug_states: ComponentResponse<HashMap<u32, DestinyItemPlugComponent>>,
-    pub objectives: ComponentResponse<HashMap<i64, DestinyItemObjectivesComponent>>,
-    pub perks: ComponentResponse<HashMap<i64, DestinyItemPerksComponent>>,
-}
-
-#[derive(Debug, Default, Clone, Copy)]
-pub enum TierType {
-    #[default]
-    Unknown = 0,
-    Currency = 1,
-    Basic = 2,
-    Common = 3,
-    Rare = 4,
-    Superior = 5,
-    Exotic = 6,
+    pub objectives: ComponentResponse<HashMap<ItemInstanceId, DestinyItemObjectivesComponent>>,
+    pub perks: ComponentResponse<HashMap<ItemInstanceId, DestinyItemPerksComponent>>,
 }
 
-impl<'de> Deserialize<'de> for TierType {
-    fn deserialize<D>(deserializer: D) -> Result<TierType, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let s = u8::deserialize(deserializer)?;
-        match s {
-            0 => Ok(TierType::Unknown),
-            1 => Ok(TierType::Currency),
-            2 => Ok(TierType::Basic),
-            3 => Ok(TierType::Common),
-            4 => Ok(TierType::Rare),
-            5 => Ok(TierType::Superior),
-            6 => Ok(TierType::Exotic),
-            _ => Err(serde::de::Error::custom(format!("unknown TierType: {s}"))),
-        }
+impl FallbackEnum for exceptions::PlatformErrorCodes {
+    fn is_unrecognized(&self) -> bool {
+        matches!(self, Self::Unknown(_))
     }
 }
 
-impl Serialize for TierType {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        let s = match self {
-            TierType::Unknown => 0,
-            TierType::Currency => 1,
-            TierType::Basic => 2,
-            TierType::Common => 3,
-            TierType::Rare => 4,
-            TierType::Superior => 5,
-            TierType::Exotic => 6,
-        };
-        s.serialize(serializer)
-    }
-}
+#[cfg(test)]
+mod tests {
+    use serde::Serialize;
+    use serde::de::DeserializeOwned;
 
-#[derive(Debug, Clone, Copy)]
-pub enum ItemLocation {
-    Unknown = 0,
-    Inventory = 1,
-    Vault = 2,
-    Vendor = 3,
-    Postmaster = 4,
-}
+    use super::*;
 
-impl<'de> Deserialize<'de> for ItemLocation {
-    fn deserialize<D>(deserializer: D) -> Result<ItemLocation, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let s = u8::deserialize(deserializer)?;
-        match s {
-            0 => Ok(ItemLocation::Unknown),
-            1 => Ok(ItemLocation::Inventory),
-            2 => Ok(ItemLocation::Vault),
-            3 => Ok(ItemLocation::Vendor),
-            4 => Ok(ItemLocation::Postmaster),
-            _ => Err(serde::de::Error::custom(format!(
-                "unknown ItemLocation: {s}"
-            ))),
-        }
+    fn round_trips<T: DeserializeOwned + Serialize + FallbackEnum>(value: i64) {
+        let parsed: T = serde_json::from_value(value.into()).unwrap();
+        assert!(parsed.is_unrecognized(), "{}", std::any::type_name::<T>());
+        assert_eq!(serde_json::to_value(parsed).unwrap(), value);
     }
-}
 
-impl Serialize for ItemLocation {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        let s = match self {
-            ItemLocation::Unknown => 0,
-            ItemLocation::Inventory => 1,
-            ItemLocation::Vault => 2,
-            ItemLocation::Vendor => 3,
-            ItemLocation::Postmaster => 4,
-        };
-        s.serialize(serializer)
+    #[test]
+    fn unrecognized_values_round_trip() {
+        let cases: [(fn(i64), i64); 46] = [
+            (round_trips::<BungieMembershipType>, 200),
+            (round_trips::<GlobalAlertLevel>, 200),
+            (round_trips::<GlobalAlertType>, 200),
+            (round_trips::<components::ComponentPrivacySetting>, 200),
+            (round_trips::<destiny::DestinyProgressionScope>, 200),
+            (
+                round_trips::<destiny::DestinyProgressionStepDisplayEffect>,
+                200,
+            ),
+            (round_trips::<destiny::SocketTypeActionType>, 200),
+            (round_trips::<destiny::DestinySocketVisibility>, 200),
+            (round_trips::<destiny::DestinySocketCategoryStyle>, 200),
+            (round_trips::<destiny::TierType>, 200),
+            (round_trips::<destiny::BucketScope>, 200),
+            (round_trips::<destiny::BucketCategory>, 200),
+            (round_trips::<destiny::ItemLocation>, 200),
+            (round_trips::<destiny::DestinyStatAggregationType>, 200),
+            (round_trips::<destiny::DestinyStatCategory>, 200),
+            (round_trips::<destiny::DestinyAmmunitionType>, 200),
+            (round_trips::<destiny::DestinyClass>, 200),
+            (round_trips::<destiny::DestinyGender>, 200),
+            (round_trips::<destiny::DestinyVendorItemRefundPolicy>, 200),
+            (round_trips::<destiny::DamageType>, 200),
+            (round_trips::<destiny::DestinyItemSubType>, 200),
+            (round_trips::<destiny::PlugAvailabilityMode>, 200),
+            (round_trips::<destiny::DestinyEnergyType>, 200),
+            (round_trips::<destiny::ItemPerkVisibility>, 200),
+            (round_trips::<destiny::SpecialItemType>, 200),
+            (round_trips::<destiny::DestinyItemType>, 200),
+            (round_trips::<destiny::DestinyBreakerType>, 200),
+            (
+                round_trips::<destiny::DestinyProgressionRewardItemAcquisitionBehavior>,
+                200,
+            ),
+            (round_trips::<destiny::ItemBindStatus>, 200),
+            (round_trips::<destiny::DestinyComponentType>, 999),
+            (round_trips::<destiny::DestinyPresentationNodeType>, 200),
+            (round_trips::<destiny::DestinyScope>, 200),
+            (round_trips::<destiny::DestinyPresentationDisplayStyle>, 200),
+            (round_trips::<destiny::DestinyPresentationScreenStyle>, 200),
+            (round_trips::<destiny::DestinyRecordValueStyle>, 200),
+            (round_trips::<destiny::DestinyRecordToastStyle>, 200),
+            (round_trips::<destiny::DestinyGamePrivacySetting>, 200),
+            (round_trips::<destiny::DestinyRace>, 200),
+            (round_trips::<destiny::DestinyActivityDifficultyTier>, 200),
+            (round_trips::<destiny::DestinyTalentNodeState>, 200),
+            (
+                round_trips::<destiny::historical_stats::definitions::DestinyActivityModeType>,
+                9999,
+            ),
+            (
+                round_trips::<destiny::historical_stats::definitions::DestinyStatsGroupType>,
+                200,
+            ),
+            (
+                round_trips::<destiny::historical_stats::definitions::PeriodType>,
+                200,
+            ),
+            (
+                round_trips::<destiny::historical_stats::definitions::DestinyStatsCategoryType>,
+                200,
+            ),
+            (
+                round_trips::<destiny::historical_stats::definitions::UnitType>,
+                200,
+            ),
+            (
+                round_trips::<destiny::historical_stats::definitions::DestinyStatsMergeMethods>,
+                200,
+            ),
+        ];
+        // Bungie types these enums as int32, so values past u8 and u16 and negative ones must
+        // survive too.
+        for (round_trips, value) in cases {
+            for value in [value, 70_000, i64::from(i32::MAX), -7] {
+                round_trips(value);
+            }
+        }
+
+        round_trips::<exceptions::PlatformErrorCodes>(999999);
+        round_trips::<exceptions::PlatformErrorCodes>(i64::from(u32::MAX));
     }
 }
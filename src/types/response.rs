@@ -6,9 +6,11 @@ use super::exceptions::PlatformErrorCodes;
 
 #[derive(Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "lenient"), serde(deny_unknown_fields))]
 pub struct BungieResponse<T> {
-    pub response: T,
+    /// Absent on error envelopes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<T>,
     pub error_code: PlatformErrorCodes,
     pub throttle_seconds: i32,
     pub error_status: String,
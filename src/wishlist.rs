@@ -0,0 +1,295 @@
+//! DIM wishlists, such as
+//! `dimwishlist:item=2938480696&perks=2420895100,3038247973#notes:PvE roll`.
+//!
+//! `title:` and `description:` lines name the list. A `//notes:` line applies to the entries
+//! after it that have no notes of their own, up to the next blank line. Other lines, including
+//! `//` comments, are ignored. A negative item hash marks a trash-list entry, and `-69420`
+//! stands for any item. Entries that cannot be read are skipped and kept in
+//! [`Wishlist::errors`], since community lists routinely carry a few malformed lines.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::inventory::OwnedItem;
+
+/// The item hash DIM uses for entries that apply to every item.
+const ANY_ITEM: i64 = -69420;
+
+#[derive(Debug, Default, Clone)]
+pub struct Wishlist {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    /// Private so that it stays in step with `by_item`; add entries with [`Wishlist::push`].
+    entries: Vec<WishlistEntry>,
+    /// The `dimwishlist:` lines that were skipped, in list order.
+    pub errors: Vec<ParseError>,
+    /// Entry indices by item hash, with `None` for wildcard entries.
+    by_item: HashMap<Option<u32>, Vec<usize>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WishlistEntry {
+    /// `None` for entries that apply to every item.
+    pub item_hash: Option<u32>,
+    /// Plug hashes that must all be on the item. Empty matches any roll.
+    pub perks: Vec<u32>,
+    pub notes: Option<String>,
+    /// A roll to dismantle rather than keep.
+    pub trash: bool,
+    /// The 1-based line the entry was read from.
+    pub line: usize,
+}
+
+#[derive(Debug)]
+pub struct WishlistMatch<'w> {
+    pub entry: &'w WishlistEntry,
+    /// Every perk is plugged in, rather than some being selectable options.
+    pub plugged: bool,
+}
+
+/// A `dimwishlist:` line that could not be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub text: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid wishlist entry on line {}: `{}`",
+            self.line, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Wishlist {
+    /// Reads a wishlist. Lines that cannot be read end up in [`Wishlist::errors`] rather than
+    /// failing the whole list.
+    pub fn parse(s: &str) -> Wishlist {
+        let mut wishlist = Wishlist::default();
+        let mut block_notes = None;
+
+        for (index, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                block_notes = None;
+            } else if let Some(notes) = line.strip_prefix("//notes:") {
+                block_notes = Some(notes.trim().to_owned());
+            } else if let Some(title) = line.strip_prefix("title:") {
+                wishlist.title = Some(title.trim().to_owned());
+            } else if let Some(description) = line.strip_prefix("description:") {
+                wishlist.description = Some(description.trim().to_owned());
+            } else if let Some(entry) = line.strip_prefix("dimwishlist:") {
+                let Some(mut entry) = parse_entry(entry) else {
+                    wishlist.errors.push(ParseError {
+                        line: index + 1,
+                        text: line.to_owned(),
+                    });
+                    continue;
+                };
+                entry.line = index + 1;
+                entry.notes = entry.notes.or_else(|| block_notes.clone());
+                wishlist.push(entry);
+            }
+        }
+
+        wishlist
+    }
+
+    /// The entries in list order, followed by any added with [`Wishlist::push`].
+    pub fn entries(&self) -> &[WishlistEntry] {
+        &self.entries
+    }
+
+    pub fn push(&mut self, entry: WishlistEntry) {
+        self.by_item
+            .entry(entry.item_hash)
+            .or_default()
+            .push(self.entries.len());
+        self.entries.push(entry);
+    }
+
+    /// The entries whose perks are all on `item`, either plugged in or among the options of
+    /// its reusable plugs component, in list order.
+    pub fn matches<'w>(&'w self, item: &OwnedItem) -> Vec<WishlistMatch<'w>> {
+        let plugged = item
+            .sockets
+            .into_iter()
+            .flat_map(|s| &s.sockets)
+            .filter(|s| s.is_enabled)
+            .map(|s| s.plug_hash)
+            .collect::<HashSet<_>>();
+        let available = item
+            .reusable_plugs
+            .into_iter()
+            .flat_map(|r| r.plugs.values().flatten())
+            .map(|p| p.plug_item_hash)
+            .chain(plugged.iter().copied())
+            .collect::<HashSet<_>>();
+
+        let mut indices = [Some(item.item.item_hash), None]
+            .iter()
+            .filter_map(|hash| self.by_item.get(hash))
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>();
+        indices.sort_unstable();
+
+        indices
+            .into_iter()
+            .map(|index| &self.entries[index])
+            .filter(|entry| entry.perks.iter().all(|p| available.contains(p)))
+            .map(|entry| WishlistMatch {
+                entry,
+                plugged: entry.perks.iter().all(|p| plugged.contains(p)),
+            })
+            .collect()
+    }
+
+    /// Whether any non-trash entry matches `item`.
+    pub fn is_wished(&self, item: &OwnedItem) -> bool {
+        self.matches(item).iter().any(|m| !m.entry.trash)
+    }
+
+    /// Whether any trash-list entry matches `item`.
+    pub fn is_trash(&self, item: &OwnedItem) -> bool {
+        self.matches(item).iter().any(|m| m.entry.trash)
+    }
+}
+
+/// Parses what follows `dimwishlist:`, leaving `line` unset.
+fn parse_entry(s: &str) -> Option<WishlistEntry> {
+    let (fields, notes) = match s.split_once("#notes:") {
+        Some((fields, notes)) => (fields, Some(notes.trim().to_owned())),
+        None => (s, None),
+    };
+
+    let mut item = None;
+    let mut perks = Vec::new();
+    for field in fields.split('&') {
+        match field.trim().split_once('=')? {
+            ("item", value) => item = Some(value.parse::<i64>().ok()?),
+            ("perks", "") => {}
+            ("perks", value) => {
+                perks = value
+                    .split(',')
+                    .map(|p| p.trim().parse())
+                    .collect::<Result<_, _>>()
+                    .ok()?;
+            }
+            _ => {}
+        }
+    }
+
+    let item = item?;
+    Some(WishlistEntry {
+        item_hash: match item {
+            ANY_ITEM => None,
+            _ => Some(u32::try_from(item.abs()).ok()?),
+        },
+        perks,
+        notes: notes.filter(|n| !n.is_empty()),
+        trash: item < 0 && item != ANY_ITEM,
+        line: 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseError, Wishlist};
+    use crate::DestinyInventoryItemManifest;
+    use crate::inventory::Inventory;
+    use crate::test_fixtures::{definitions, profile};
+    use crate::types::ids::ItemInstanceId;
+
+    const WISHLIST: &str = "\
+title:Sample rolls
+description:Rolls for the fixture profile
+
+//notes:Outlaw and Rampage is the classic.
+dimwishlist:item=2938480696&perks=2420895100,3038247973
+dimwishlist:item=2938480696&perks=2779035018,1015611457#notes:Feeding Frenzy and Kill Clip
+
+// Anything with Rampage.
+dimwishlist:item=-69420&perks=3038247973
+dimwishlist:item=-2938480696&perks=2779035018,3038247973#notes:Not worth keeping
+dimwishlist:item=2938480696&perks=1392496348
+dimwishlist:item=1363886209
+";
+
+    #[test]
+    fn parses() {
+        let wishlist = Wishlist::parse(WISHLIST);
+        assert_eq!(wishlist.title.as_deref(), Some("Sample rolls"));
+        assert_eq!(wishlist.entries().len(), 6);
+
+        let classic = &wishlist.entries()[0];
+        assert_eq!(classic.item_hash, Some(2938480696));
+        assert_eq!(classic.perks, [2420895100, 3038247973]);
+        assert_eq!(
+            classic.notes.as_deref(),
+            Some("Outlaw and Rampage is the classic.")
+        );
+        assert_eq!(classic.line, 5);
+        assert_eq!(
+            wishlist.entries()[1].notes.as_deref(),
+            Some("Feeding Frenzy and Kill Clip")
+        );
+
+        assert_eq!(wishlist.entries()[2].item_hash, None);
+        assert_eq!(wishlist.entries()[2].notes, None);
+        assert!(!wishlist.entries()[2].trash);
+        assert!(wishlist.entries()[3].trash);
+        assert!(wishlist.entries()[5].perks.is_empty());
+
+        assert!(wishlist.errors.is_empty());
+
+        let wishlist = Wishlist::parse(
+            "dimwishlist:item=12&perks=1,x\ndimwishlist:item=12&perks=1\ndimwishlist:perks=1",
+        );
+        assert_eq!(wishlist.entries().len(), 1);
+        assert_eq!(wishlist.entries()[0].line, 2);
+        assert_eq!(
+            wishlist.errors,
+            [
+                ParseError {
+                    line: 1,
+                    text: String::from("dimwishlist:item=12&perks=1,x"),
+                },
+                ParseError {
+                    line: 3,
+                    text: String::from("dimwishlist:perks=1"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn matches_plugs() {
+        let profile = profile("profile.json");
+        let definitions: DestinyInventoryItemManifest =
+            definitions("DestinyInventoryItemDefinition");
+        let inventory = Inventory::new(&profile, &definitions);
+        let wishlist = Wishlist::parse(WISHLIST);
+
+        let vaulted = inventory.get(ItemInstanceId(6917529800000000003)).unwrap();
+        let matches = wishlist
+            .matches(vaulted)
+            .into_iter()
+            .map(|m| (m.entry.line, m.plugged))
+            .collect::<Vec<_>>();
+        assert_eq!(matches, [(5, true), (6, false), (9, true), (10, false)]);
+        assert!(wishlist.is_wished(vaulted));
+        assert!(wishlist.is_trash(vaulted));
+
+        let weapon = inventory.get(ItemInstanceId(6917529800000000001)).unwrap();
+        let matches = wishlist.matches(weapon);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].entry.line, 12);
+        assert!(!wishlist.is_trash(weapon));
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use bungie_api::types::components::ComponentUnavailable;
+use bungie_api::types::destiny::config::DestinyManifest;
+use bungie_api::types::destiny::historical_stats::definitions::DestinyHistoricalStatsDefinition;
+use bungie_api::types::destiny::historical_stats::{
+    DestinyActivityHistoryResults, DestinyAggregateActivityResults, DestinyClanAggregateStat,
+    DestinyHistoricalStatsAccountResult, DestinyHistoricalStatsByPeriod,
+    DestinyHistoricalWeaponStatsData, DestinyLeaderboard, DestinyPostGameCarnageReportData,
+};
+use bungie_api::types::destiny::milestones::{
+    DestinyMilestone, DestinyMilestoneContent, DestinyPublicMilestone,
+};
+use bungie_api::types::destiny::responses::DestinyProfileResponse;
+use bungie_api::types::exceptions::PlatformErrorCodes;
+use bungie_api::types::response::BungieResponse;
+use bungie_api::types::user::UserInfoCard;
+use bungie_api::{
+    DestinyActivityManifest, DestinyActivityModifierManifest, DestinyInventoryItemManifest,
+    DestinyMilestoneManifest, DestinyPlugSetManifest, DestinyPresentationNodeManifest,
+    DestinyRecordManifest, DestinySocketCategoryManifest, DestinySocketTypeManifest,
+    DestinyVendorManifest,
+};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+fn fixture(name: &str) -> Value {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name);
+    let text = fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+    serde_json::from_str(&text).unwrap()
+}
+
+/// Nulls are dropped since absent and null options deserialize the same, and numbers compare as
+/// floats since a whole-valued `f64` serializes as `1.0` where the fixture may say `1`.
+fn normalize(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, v)| (k, normalize(v)))
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(values.into_iter().map(normalize).collect()),
+        Value::Number(n) => Value::from(n.as_f64().unwrap()),
+        value => value,
+    }
+}
+
+/// Deserializes the fixture as `T`, then checks it serializes back to the same document.
+fn round_trip<T: DeserializeOwned + Serialize>(name: &str) -> T {
+    let original = fixture(name);
+    let parsed = serde_path_to_error::deserialize::<_, T>(original.clone())
+        .unwrap_or_else(|e| panic!("{name}: {e}"));
+    let reserialized = serde_json::to_value(&parsed).unwrap();
+    assert_eq!(
+        normalize(reserialized),
+        normalize(original),
+        "{name} did not round-trip"
+    );
+    parsed
+}
+
+#[test]
+fn manifest() {
+    let res = round_trip::<BungieResponse<DestinyManifest>>("manifest.json");
+    assert!(
+        res.response.unwrap().json_world_component_content_paths["en"]
+            .contains_key("DestinyInventoryItemDefinition")
+    );
+}
+
+#[test]
+fn profile() {
+    let res = round_trip::<BungieResponse<DestinyProfileResponse>>("profile.json");
+    let profile = res.response.unwrap();
+    let character_id = profile.profile().unwrap().character_ids[0];
+    let equipment = &profile.character_equipment().unwrap()[&character_id];
+    let instance_id = equipment.items[0].item_instance_id.unwrap();
+    let instances = profile.item_components.unwrap().instances;
+    assert!(instances.data().unwrap()[&instance_id].is_equipped);
+}
+
+/// Vaulted armor with an artifice socket and an exotic class item with a disabled mod.
+#[test]
+fn profile_armor() {
+    let res = round_trip::<BungieResponse<DestinyProfileResponse>>("profile_armor.json");
+    let profile = res.response.unwrap();
+    assert_eq!(profile.profile_inventory().unwrap().items.len(), 2);
+    let sockets = profile.item_components.unwrap().sockets;
+    assert!(
+        sockets
+            .data()
+            .unwrap()
+            .values()
+            .any(|s| !s.sockets[3].is_enabled)
+    );
+}
+
+/// A profile whose owner hides their inventories, with records disabled by Bungie and transitory
+/// data sent without a reason.
+#[test]
+fn profile_unavailable_components() {
+    let res = round_trip::<BungieResponse<DestinyProfileResponse>>("profile_unavailable.json");
+    let profile = res.response.unwrap();
+    assert!(profile.profile().is_ok());
+    assert_eq!(
+        profile.character_inventories().unwrap_err(),
+        ComponentUnavailable::Private
+    );
+    assert_eq!(
+        profile.character_equipment().unwrap_err(),
+        ComponentUnavailable::Private
+    );
+    assert_eq!(
+        profile.profile_records().unwrap_err(),
+        ComponentUnavailable::Disabled
+    );
+    assert_eq!(
+        profile.profile_transitory_data().unwrap_err(),
+        ComponentUnavailable::Missing
+    );
+    assert_eq!(
+        profile.characters().unwrap_err(),
+        ComponentUnavailable::NotRequested
+    );
+    let instances = profile.item_components.unwrap().instances;
+    assert_eq!(instances.data().unwrap_err(), ComponentUnavailable::Private);
+}
+
+#[test]
+fn post_game_carnage_report() {
+    round_trip::<BungieResponse<DestinyPostGameCarnageReportData>>("pgcr.json");
+}
+
+#[test]
+fn activity_history() {
+    round_trip::<BungieResponse<DestinyActivityHistoryResults>>("activity_history.json");
+}
+
+#[test]
+fn historical_stats() {
+    round_trip::<BungieResponse<HashMap<String, DestinyHistoricalStatsDefinition>>>(
+        "historical_stats_definition.json",
+    );
+    round_trip::<BungieResponse<DestinyHistoricalStatsAccountResult>>(
+        "historical_stats_account.json",
+    );
+    round_trip::<BungieResponse<HashMap<String, DestinyHistoricalStatsByPeriod>>>(
+        "historical_stats.json",
+    );
+    round_trip::<BungieResponse<DestinyAggregateActivityResults>>("aggregate_activity_stats.json");
+    round_trip::<BungieResponse<DestinyHistoricalWeaponStatsData>>("unique_weapons.json");
+}
+
+#[test]
+fn clans() {
+    round_trip::<BungieResponse<DestinyMilestone>>("clan_weekly_reward_state.json");
+    round_trip::<BungieResponse<Vec<DestinyClanAggregateStat>>>("clan_aggregate_stats.json");
+    round_trip::<BungieResponse<HashMap<String, HashMap<String, DestinyLeaderboard>>>>(
+        "clan_leaderboards.json",
+    );
+}
+
+#[test]
+fn milestones() {
+    round_trip::<BungieResponse<HashMap<u32, DestinyPublicMilestone>>>("milestones.json");
+    round_trip::<BungieResponse<DestinyMilestoneContent>>("milestone_content.json");
+}
+
+#[test]
+fn search_destiny_player() {
+    round_trip::<BungieResponse<Vec<UserInfoCard>>>("search_destiny_player.json");
+}
+
+#[test]
+fn definitions() {
+    round_trip::<DestinyActivityManifest>("definitions/DestinyActivityDefinition.json");
+    round_trip::<DestinyActivityModifierManifest>(
+        "definitions/DestinyActivityModifierDefinition.json",
+    );
+    round_trip::<DestinyMilestoneManifest>("definitions/DestinyMilestoneDefinition.json");
+    round_trip::<DestinyVendorManifest>("definitions/DestinyVendorDefinition.json");
+    round_trip::<DestinyInventoryItemManifest>("definitions/DestinyInventoryItemDefinition.json");
+    round_trip::<DestinyPlugSetManifest>("definitions/DestinyPlugSetDefinition.json");
+    round_trip::<DestinyRecordManifest>("definitions/DestinyRecordDefinition.json");
+    round_trip::<DestinyPresentationNodeManifest>(
+        "definitions/DestinyPresentationNodeDefinition.json",
+    );
+    round_trip::<DestinySocketCategoryManifest>("definitions/DestinySocketCategoryDefinition.json");
+    round_trip::<DestinySocketTypeManifest>("definitions/DestinySocketTypeDefinition.json");
+}
+
+#[test]
+fn error_envelopes() {
+    for (name, code) in [
+        ("error_system_disabled.json", 5),
+        ("error_account_not_found.json", 1601),
+        ("error_throttled.json", 36),
+    ] {
+        let res = round_trip::<BungieResponse<DestinyProfileResponse>>(name);
+        assert!(res.response.is_none());
+        assert!(matches!(res.error_code, PlatformErrorCodes::Unknown(c) if c == code));
+    }
+}
@@ -0,0 +1,909 @@
+//! Development tasks for `bungie-api`.
+//!
+//! `cargo xtask codegen [openapi.json]` generates models and endpoints from a snapshot of Bungie's
+//! OpenAPI spec. Schemas are placed by namespace into the existing module tree, so
+//! `Destiny.HistoricalStats.Definitions.DestinyActivityModeType` lands in
+//! `src/types/destiny/historical_stats/definitions/generated.rs`, falling back to the closest
+//! module that exists. Each touched module gets `mod generated; pub use generated::*;`.
+//!
+//! Schemas listed in [`HAND_WRITTEN_SCHEMAS`] are modelled by hand: they are referenced at the
+//! path given there and not emitted. Operations listed in [`HAND_WRITTEN_OPERATIONS`] already have
+//! a `BungieClient` method and are skipped. Only GET operations are generated; `BungieClient`
+//! sends an API key but no OAuth token, which Bungie's POST operations require.
+//!
+//! The committed `generated.rs` files come from `xtask/openapi.json`, a partial copy of Bungie's
+//! spec that is the default input. It holds every enum and bitflags type the crate models, while
+//! most structs are still written by hand. To generate more, copy the schemas and paths from
+//! Bungie's spec into it, drop the hand-written copies and rerun; a test fails when the committed
+//! files no longer match the snapshot.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write as _;
+use std::fs;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode, Stdio};
+
+use serde_json::{Map, Value};
+
+/// Schemas modelled by hand, with their path under `crate::types`.
+const HAND_WRITTEN_SCHEMAS: &[(&str, &str)] = &[
+    (
+        "Destiny.Definitions.Common.DestinyDisplayPropertiesDefinition",
+        "common::DestinyDisplayPropertiesDefinition",
+    ),
+    (
+        "Destiny.Definitions.Common.DestinyIconSequenceDefinition",
+        "common::DestinyIconSequenceDefinition",
+    ),
+    ("Destiny.Misc.DestinyColor", "misc::DestinyColor"),
+];
+
+/// Operations that `BungieClient` implements by hand.
+const HAND_WRITTEN_OPERATIONS: &[&str] = &[
+    "Destiny2.GetActivityHistory",
+    "Destiny2.GetClanAggregateStats",
+    "Destiny2.GetClanLeaderboards",
+    "Destiny2.GetClanWeeklyRewardState",
+    "Destiny2.GetDestinyAggregateActivityStats",
+    "Destiny2.GetDestinyEntityDefinition",
+    "Destiny2.GetDestinyManifest",
+    "Destiny2.GetHistoricalStats",
+    "Destiny2.GetHistoricalStatsDefinition",
+    "Destiny2.GetHistoricalStatsForAccount",
+    "Destiny2.GetPostGameCarnageReport",
+    "Destiny2.GetProfile",
+    "Destiny2.GetPublicMilestoneContent",
+    "Destiny2.GetPublicMilestones",
+    "Destiny2.GetUniqueWeaponHistory",
+    "Destiny2.SearchDestinyPlayerByBungieName",
+];
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("codegen") => {
+            let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+            let spec_path = args
+                .get(1)
+                .map(PathBuf::from)
+                .unwrap_or_else(|| root.join("xtask").join("openapi.json"));
+
+            match codegen(root, &spec_path) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(e) => {
+                    eprintln!("codegen failed: {e}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        _ => {
+            eprintln!("usage: cargo xtask codegen [path/to/openapi.json]");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// A generated file and the module file that declares it.
+struct Output {
+    path: PathBuf,
+    module_file: PathBuf,
+    /// Whether the module re-exports the file's items, which it does for types.
+    reexport: bool,
+    text: String,
+}
+
+fn codegen(root: &Path, spec_path: &Path) -> Result<(), String> {
+    let text = fs::read_to_string(spec_path)
+        .map_err(|e| format!("cannot read {}: {e}", spec_path.display()))?;
+    let spec: Value = serde_json::from_str(&text).map_err(|e| e.to_string())?;
+
+    for output in generate(root, &spec)? {
+        if let Some(parent) = output.path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        fs::write(&output.path, &output.text)
+            .map_err(|e| format!("cannot write {}: {e}", output.path.display()))?;
+        wire_module(&output.module_file, output.reexport)?;
+        println!("wrote {}", output.path.display());
+    }
+
+    Ok(())
+}
+
+/// Renders and formats every generated file without touching the tree.
+fn generate(root: &Path, spec: &Value) -> Result<Vec<Output>, String> {
+    let src = root.join("src");
+    let generator = Generator::new(&src, spec)?;
+
+    let mut outputs = Vec::new();
+
+    for (module, items) in generator.types() {
+        outputs.push(Output {
+            path: generator.generated_path(&module),
+            module_file: generator.module_file(&module),
+            reexport: true,
+            text: rustfmt(&module_text(&items))?,
+        });
+    }
+
+    let endpoints = generator.endpoints();
+    if !endpoints.is_empty() {
+        outputs.push(Output {
+            path: src.join("endpoints").join("generated.rs"),
+            module_file: src.join("endpoints").join("mod.rs"),
+            reexport: false,
+            text: rustfmt(&module_text(&endpoints))?,
+        });
+    }
+
+    Ok(outputs)
+}
+
+fn rustfmt(code: &str) -> Result<String, String> {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", "2024", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("cannot run rustfmt: {e}"))?;
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(code.as_bytes())
+        .map_err(|e| e.to_string())?;
+
+    let output = child.wait_with_output().map_err(|e| e.to_string())?;
+    if !output.status.success() {
+        return Err("rustfmt failed on generated code".to_string());
+    }
+    String::from_utf8(output.stdout).map_err(|e| e.to_string())
+}
+
+fn module_text(body: &str) -> String {
+    let mut out = String::from("// @generated by `cargo xtask codegen`. Do not edit by hand.\n\n");
+
+    if body.contains("BungieClient") {
+        out.push_str("#![allow(clippy::too_many_arguments)]\n\n");
+    }
+
+    for (needle, import) in [
+        ("HashMap<", "use std::collections::HashMap;"),
+        ("DateTime<Utc>", "use chrono::{DateTime, Utc};"),
+        ("bitflags!", "use bitflags::bitflags;"),
+        ("BungieClient", "use crate::{BungieClient, Result};"),
+    ] {
+        if body.contains(needle) {
+            out.push_str(import);
+            out.push('\n');
+        }
+    }
+
+    let serde: Vec<&str> = ["Deserialize", "Deserializer", "Serialize", "Serializer"]
+        .into_iter()
+        .filter(|name| {
+            body.contains(&format!("{name})"))
+                || body.contains(&format!("{name},"))
+                || body.contains(&format!("{name}<"))
+                || body.contains(&format!("{name} for"))
+                || body.contains(&format!("{name}::"))
+        })
+        .collect();
+    if !serde.is_empty() {
+        let _ = writeln!(out, "use serde::{{{}}};", serde.join(", "));
+    }
+
+    out.push('\n');
+    out.push_str(body);
+    out
+}
+
+/// Declares the `generated` submodule in `module_file`, re-exporting it when it holds types.
+fn wire_module(module_file: &Path, reexport: bool) -> Result<(), String> {
+    let text = fs::read_to_string(module_file)
+        .map_err(|e| format!("cannot read {}: {e}", module_file.display()))?;
+
+    if let Some(text) = wired(&text, reexport) {
+        fs::write(module_file, text)
+            .map_err(|e| format!("cannot write {}: {e}", module_file.display()))?;
+    }
+    Ok(())
+}
+
+/// `text` with the `generated` submodule declared, or `None` when it already is.
+fn wired(text: &str, reexport: bool) -> Option<String> {
+    if text.lines().any(|line| line.trim() == "mod generated;") {
+        return None;
+    }
+
+    let mut lines: Vec<&str> = text.lines().collect();
+    let insert_at = lines
+        .iter()
+        .rposition(|line| {
+            let line = line.trim_start_matches("pub ");
+            line.starts_with("mod ") && line.ends_with(';')
+        })
+        .map(|i| i + 1)
+        .unwrap_or(0);
+
+    let mut declaration = vec!["mod generated;"];
+    if reexport {
+        declaration.push("pub use generated::*;");
+    }
+    if insert_at == 0 {
+        declaration.push("");
+    }
+    lines.splice(insert_at..insert_at, declaration);
+
+    let mut text = lines.join("\n");
+    text.push('\n');
+    Some(text)
+}
+
+/// Where a schema's type lives.
+#[derive(Clone)]
+struct Placement {
+    /// Module path under `crate::types`.
+    module: Vec<String>,
+    name: String,
+    /// `false` for hand-written types, which are referenced but not emitted.
+    generated: bool,
+}
+
+impl Placement {
+    fn path(&self) -> String {
+        let mut path = String::from("crate::types");
+        for segment in &self.module {
+            path.push_str("::");
+            path.push_str(segment);
+        }
+        path.push_str("::");
+        path.push_str(&self.name);
+        path
+    }
+}
+
+struct Generator<'a> {
+    src: PathBuf,
+    schemas: &'a Map<String, Value>,
+    responses: Option<&'a Map<String, Value>>,
+    paths: Option<&'a Map<String, Value>>,
+    placements: HashMap<&'a str, Placement>,
+}
+
+impl<'a> Generator<'a> {
+    fn new(src: &Path, spec: &'a Value) -> Result<Self, String> {
+        let schemas = spec["components"]["schemas"]
+            .as_object()
+            .ok_or("spec has no components.schemas")?;
+
+        let mut generator = Generator {
+            src: src.to_path_buf(),
+            schemas,
+            responses: spec["components"]["responses"].as_object(),
+            paths: spec["paths"].as_object(),
+            placements: HashMap::new(),
+        };
+
+        let mut taken: HashSet<(Vec<String>, String)> = HashSet::new();
+
+        for key in schemas.keys() {
+            let hand_written = HAND_WRITTEN_SCHEMAS
+                .iter()
+                .find(|(schema, _)| schema == key)
+                .map(|(_, path)| path);
+
+            let placement = match hand_written {
+                Some(path) => {
+                    let mut module: Vec<String> = path.split("::").map(String::from).collect();
+                    let name = module.pop().unwrap();
+                    Placement {
+                        module,
+                        name,
+                        generated: false,
+                    }
+                }
+                None => {
+                    let name = type_name(key);
+                    let namespace: Vec<&str> = key.split('.').collect();
+                    let module = generator.closest_module(&namespace[..namespace.len() - 1]);
+                    if !taken.insert((module.clone(), name.clone())) {
+                        eprintln!("skipping {key}: {name} is already generated in this module");
+                        continue;
+                    }
+                    Placement {
+                        module,
+                        name,
+                        generated: true,
+                    }
+                }
+            };
+
+            generator.placements.insert(key, placement);
+        }
+
+        Ok(generator)
+    }
+
+    /// The deepest existing module along a schema namespace.
+    fn closest_module(&self, namespace: &[&str]) -> Vec<String> {
+        let mut module = Vec::new();
+        for segment in namespace {
+            let mut candidate = module.clone();
+            candidate.push(snake_case(segment));
+            if !self.module_file(&candidate).exists() {
+                break;
+            }
+            module = candidate;
+        }
+        module
+    }
+
+    fn module_dir(&self, module: &[String]) -> PathBuf {
+        let mut dir = self.src.join("types");
+        for segment in module {
+            dir.push(segment);
+        }
+        dir
+    }
+
+    fn module_file(&self, module: &[String]) -> PathBuf {
+        let dir = self.module_dir(module);
+        if module.is_empty() || dir.join("mod.rs").exists() {
+            dir.join("mod.rs")
+        } else {
+            dir.with_extension("rs")
+        }
+    }
+
+    fn generated_path(&self, module: &[String]) -> PathBuf {
+        self.module_dir(module).join("generated.rs")
+    }
+
+    /// Generated type definitions grouped by target module.
+    fn types(&self) -> BTreeMap<Vec<String>, String> {
+        let mut modules: BTreeMap<Vec<String>, String> = BTreeMap::new();
+
+        for (key, schema) in self.schemas {
+            let Some(placement) = self.placements.get(key.as_str()) else {
+                continue;
+            };
+            if !placement.generated {
+                continue;
+            }
+
+            let out = modules.entry(placement.module.clone()).or_default();
+            if schema.get("enum").is_some() {
+                if schema["x-enum-is-bitmask"].as_bool().unwrap_or(false) {
+                    self.bitflags(out, &placement.name, schema);
+                } else {
+                    self.enumeration(out, &placement.name, schema);
+                }
+            } else if schema.get("properties").is_some() {
+                self.structure(out, placement, schema);
+            }
+        }
+
+        modules.retain(|_, body| !body.is_empty());
+        modules
+    }
+
+    fn structure(&self, out: &mut String, placement: &Placement, schema: &Value) {
+        doc(out, schema, "");
+        out.push_str("#[derive(Debug, Deserialize, Serialize)]\n");
+        out.push_str("#[serde(rename_all = \"camelCase\")]\n");
+        out.push_str("#[cfg_attr(not(feature = \"lenient\"), serde(deny_unknown_fields))]\n");
+        let _ = writeln!(out, "pub struct {} {{", placement.name);
+
+        for (property, value) in schema["properties"].as_object().unwrap() {
+            let (field, renamed) = field_name(property);
+            let mut ty = id_type(property, self.rust_type(value, Some(&placement.module)));
+
+            doc(out, value, "    ");
+            if renamed {
+                let _ = writeln!(out, "    #[serde(rename = \"{property}\")]");
+            }
+            if ty.starts_with("Vec<") || ty.starts_with("HashMap<") {
+                out.push_str("    #[serde(default)]\n");
+            } else if value["nullable"].as_bool().unwrap_or(false) {
+                ty = format!("Option<{ty}>");
+            }
+            let _ = writeln!(out, "    pub {field}: {ty},");
+        }
+
+        out.push_str("}\n\n");
+    }
+
+    fn enumeration(&self, out: &mut String, name: &str, schema: &Value) {
+        let repr = integer_type(schema["format"].as_str().unwrap_or("int32"));
+        let variants = enum_values(schema);
+
+        doc(out, schema, "");
+        out.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n");
+        let _ = writeln!(out, "pub enum {name} {{");
+        for (variant, _, description) in &variants {
+            if !description.is_empty() {
+                doc_lines(out, description, "    ");
+            }
+            let _ = writeln!(out, "    {variant},");
+        }
+        if variants.iter().any(|(variant, _, _)| variant == "Unknown") {
+            out.push_str(
+                "    /// A value not known to this crate. `Unknown` is a value Bungie defines.\n",
+            );
+        } else {
+            out.push_str("    /// A value not known to this crate.\n");
+        }
+        let _ = writeln!(out, "    Unrecognized({repr}),");
+        out.push_str("}\n\n");
+
+        let _ = writeln!(out, "impl<'de> Deserialize<'de> for {name} {{");
+        let _ = writeln!(
+            out,
+            "    fn deserialize<D>(deserializer: D) -> Result<{name}, D::Error>"
+        );
+        out.push_str("    where\n        D: Deserializer<'de>,\n    {\n");
+        let _ = writeln!(out, "        let s = {repr}::deserialize(deserializer)?;");
+        out.push_str("        match s {\n");
+        for (variant, value, _) in &variants {
+            let _ = writeln!(out, "            {value} => Ok({name}::{variant}),");
+        }
+        let _ = writeln!(out, "            _ => Ok({name}::Unrecognized(s)),");
+        out.push_str("        }\n    }\n}\n\n");
+
+        let _ = writeln!(out, "impl Serialize for {name} {{");
+        out.push_str("    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>\n");
+        out.push_str("    where\n        S: Serializer,\n    {\n");
+        let _ = writeln!(out, "        {repr}::from(*self).serialize(serializer)");
+        out.push_str("    }\n}\n\n");
+
+        let _ = writeln!(out, "impl From<{name}> for {repr} {{");
+        let _ = writeln!(out, "    fn from(value: {name}) -> {repr} {{");
+        out.push_str("        match value {\n");
+        for (variant, value, _) in &variants {
+            let _ = writeln!(out, "            {name}::{variant} => {value},");
+        }
+        let _ = writeln!(out, "            {name}::Unrecognized(value) => value,");
+        out.push_str("        }\n    }\n}\n\n");
+
+        let _ = writeln!(out, "impl crate::types::FallbackEnum for {name} {{");
+        out.push_str("    fn is_unrecognized(&self) -> bool {\n");
+        out.push_str("        matches!(self, Self::Unrecognized(_))\n");
+        out.push_str("    }\n}\n\n");
+    }
+
+    fn bitflags(&self, out: &mut String, name: &str, schema: &Value) {
+        // Flags are bits, so they read as the unsigned type of the same width.
+        let repr = match integer_type(schema["format"].as_str().unwrap_or("int32")) {
+            "i8" => "u8",
+            "i16" => "u16",
+            "i32" => "u32",
+            "i64" => "u64",
+            repr => repr,
+        };
+
+        out.push_str("bitflags! {\n");
+        doc(out, schema, "    ");
+        out.push_str("    #[derive(Debug, Clone, Copy, PartialEq, Eq)]\n");
+        let _ = writeln!(out, "    pub struct {name}: {repr} {{");
+        for (flag, value, description) in enum_values(schema) {
+            if !description.is_empty() {
+                doc_lines(out, &description, "        ");
+            }
+            let _ = writeln!(out, "        const {flag} = {value};");
+        }
+        out.push_str("    }\n}\n\n");
+
+        let _ = writeln!(out, "impl<'de> Deserialize<'de> for {name} {{");
+        let _ = writeln!(
+            out,
+            "    fn deserialize<D>(deserializer: D) -> Result<{name}, D::Error>"
+        );
+        out.push_str("    where\n        D: Deserializer<'de>,\n    {\n");
+        let _ = writeln!(out, "        let s = {repr}::deserialize(deserializer)?;");
+        let _ = writeln!(out, "        Ok({name}::from_bits_retain(s))");
+        out.push_str("    }\n}\n\n");
+
+        let _ = writeln!(out, "impl Serialize for {name} {{");
+        out.push_str("    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>\n");
+        out.push_str("    where\n        S: Serializer,\n    {\n");
+        out.push_str("        self.bits().serialize(serializer)\n");
+        out.push_str("    }\n}\n\n");
+    }
+
+    /// The Rust type for a property or parameter schema, as seen from `module`.
+    fn rust_type(&self, schema: &Value, module: Option<&[String]>) -> String {
+        let target = schema["allOf"]
+            .as_array()
+            .and_then(|all| all.first())
+            .unwrap_or(schema);
+
+        let reference = target["$ref"]
+            .as_str()
+            .or_else(|| target["x-enum-reference"]["$ref"].as_str());
+
+        if let Some(reference) = reference {
+            let key = reference.rsplit('/').next().unwrap();
+            return match self.placements.get(key) {
+                Some(placement)
+                    if placement.generated && Some(placement.module.as_slice()) == module =>
+                {
+                    placement.name.clone()
+                }
+                Some(placement) => placement.path(),
+                None => "serde_json::Value".to_string(),
+            };
+        }
+
+        match target["type"].as_str() {
+            Some("array") => format!("Vec<{}>", self.rust_type(&target["items"], module)),
+            Some("object") if target.get("additionalProperties").is_some() => {
+                let key = target
+                    .get("x-dictionary-key")
+                    .map(|key| self.rust_type(key, module))
+                    .unwrap_or_else(|| "String".to_string());
+                let value = self.rust_type(&target["additionalProperties"], module);
+                format!("HashMap<{key}, {value}>")
+            }
+            Some("integer") => {
+                integer_type(target["format"].as_str().unwrap_or("int32")).to_string()
+            }
+            Some("number") if target["format"] == "float" => "f32".to_string(),
+            Some("number") => "f64".to_string(),
+            Some("boolean") => "bool".to_string(),
+            Some("string") if target["format"] == "date-time" => "DateTime<Utc>".to_string(),
+            Some("string") => "String".to_string(),
+            _ => "serde_json::Value".to_string(),
+        }
+    }
+
+    /// Generated `BungieClient` methods for every GET operation not in
+    /// [`HAND_WRITTEN_OPERATIONS`]. Other methods are out of scope, see the crate docs.
+    fn endpoints(&self) -> String {
+        let mut out = String::new();
+        let Some(paths) = self.paths else {
+            return out;
+        };
+
+        let mut names = HashSet::new();
+        let mut methods = String::new();
+
+        for (path, item) in paths {
+            let Some(operation) = item.get("get") else {
+                continue;
+            };
+            let Some(operation_id) = operation["operationId"].as_str() else {
+                continue;
+            };
+            if HAND_WRITTEN_OPERATIONS.contains(&operation_id) {
+                continue;
+            }
+
+            let (tag, action) = operation_id.split_once('.').unwrap_or(("", operation_id));
+            let mut name = snake_case(action);
+            if names.contains(&name) {
+                name = format!("{}_{name}", snake_case(tag));
+            }
+            if !names.insert(name.clone()) {
+                continue;
+            }
+
+            self.endpoint(&mut methods, &name, path, operation);
+        }
+
+        if methods.is_empty() {
+            return out;
+        }
+
+        out.push_str("impl BungieClient {\n");
+        out.push_str(&methods);
+        out.push_str("}\n\n");
+        out.push_str("/// Formats a path or query value the way it serializes, without quotes.\n");
+        out.push_str("fn query_value<T: serde::Serialize>(value: &T) -> String {\n");
+        out.push_str("    match serde_json::to_value(value).unwrap() {\n");
+        out.push_str("        serde_json::Value::String(s) => s,\n");
+        out.push_str("        value => value.to_string(),\n");
+        out.push_str("    }\n}\n");
+        out
+    }
+
+    fn endpoint(&self, out: &mut String, name: &str, path: &str, operation: &Value) {
+        let parameters: Vec<&Value> = operation["parameters"]
+            .as_array()
+            .map(|p| p.iter().collect())
+            .unwrap_or_default();
+
+        let mut arguments = String::new();
+        let mut segments = String::new();
+        let mut query = String::new();
+
+        for segment in path.trim_matches('/').split('/') {
+            match segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+                Some(parameter) => {
+                    let schema = parameters
+                        .iter()
+                        .find(|p| p["name"] == parameter)
+                        .map(|p| &p["schema"])
+                        .unwrap_or(&Value::Null);
+                    let (argument, _) = field_name(parameter);
+                    let ty = match id_type(parameter, self.rust_type(schema, None)) {
+                        ty if ty == "String" => "&str".to_string(),
+                        ty => ty,
+                    };
+                    let _ = write!(arguments, ", {argument}: {ty}");
+                    let _ = write!(segments, ".push(&query_value(&{argument}))");
+                }
+                None => {
+                    let _ = write!(segments, ".push({segment:?})");
+                }
+            }
+        }
+
+        for parameter in parameters.iter().filter(|p| p["in"] == "query") {
+            let query_name = parameter["name"].as_str().unwrap();
+            let (argument, _) = field_name(query_name);
+            let schema = &parameter["schema"];
+
+            if schema["type"] == "array" {
+                let item = self.rust_type(&schema["items"], None);
+                let _ = write!(arguments, ", {argument}: &[{item}]");
+                let _ = write!(
+                    query,
+                    "if !{argument}.is_empty() {{\n\
+                     let joined = {argument}.iter().map(query_value).collect::<Vec<_>>().join(\",\");\n\
+                     query_pairs.append_pair({query_name:?}, &joined);\n}}\n"
+                );
+            } else {
+                let ty = match id_type(query_name, self.rust_type(schema, None)) {
+                    ty if ty == "String" => "&str".to_string(),
+                    ty => ty,
+                };
+                let _ = write!(arguments, ", {argument}: Option<{ty}>");
+                let _ = write!(
+                    query,
+                    "if let Some({argument}) = {argument} {{\n\
+                     query_pairs.append_pair({query_name:?}, &query_value(&{argument}));\n}}\n"
+                );
+            }
+        }
+
+        let response = self.response_type(operation);
+
+        doc_lines(
+            out,
+            operation["summary"].as_str().unwrap_or_default(),
+            "    ",
+        );
+        let _ = writeln!(
+            out,
+            "pub async fn {name}(&self{arguments}) -> Result<{response}> {{"
+        );
+        out.push_str("let mut url = self.url(\"Platform/\");\n");
+        let _ = writeln!(
+            out,
+            "url.path_segments_mut().expect(\"Cannot set path segments\").pop_if_empty(){segments}.push(\"\");"
+        );
+        if !query.is_empty() {
+            let _ = writeln!(
+                out,
+                "{{\nlet mut query_pairs = url.query_pairs_mut();\n{query}}}"
+            );
+        }
+        let _ = writeln!(
+            out,
+            "self.get_bungie_response::<{response}>(url).await\n}}\n"
+        );
+    }
+
+    fn response_type(&self, operation: &Value) -> String {
+        let response = operation["responses"]["200"]["$ref"]
+            .as_str()
+            .and_then(|r| r.rsplit('/').next())
+            .and_then(|key| self.responses?.get(key));
+
+        match response {
+            Some(response) => self.rust_type(
+                &response["content"]["application/json"]["schema"]["properties"]["Response"],
+                None,
+            ),
+            None => "serde_json::Value".to_string(),
+        }
+    }
+}
+
+fn enum_values(schema: &Value) -> Vec<(String, String, String)> {
+    let mut seen = HashSet::new();
+
+    schema["x-enum-values"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|v| {
+            let value = match &v["numericValue"] {
+                Value::String(s) => s.clone(),
+                value => value.to_string(),
+            };
+            let identifier = type_name(v["identifier"].as_str()?);
+            let identifier = if identifier.starts_with(|c: char| c.is_ascii_digit()) {
+                format!("_{identifier}")
+            } else {
+                identifier
+            };
+            seen.insert(value.clone()).then(|| {
+                let description = v["description"].as_str().unwrap_or_default().to_string();
+                (identifier, value, description)
+            })
+        })
+        .collect()
+}
+
+/// Swaps int64 IDs for the newtypes in `crate::types::ids`, going by the property name.
+fn id_type(property: &str, ty: String) -> String {
+    if ty != "i64" {
+        return ty;
+    }
+
+    let property = property.to_ascii_lowercase();
+    let id = if property.ends_with("membershipid") {
+        "MembershipId"
+    } else if property.ends_with("characterid") {
+        "CharacterId"
+    } else if property.ends_with("iteminstanceid") || property == "itemid" {
+        "ItemInstanceId"
+    } else if property == "instanceid" || property.ends_with("activityid") {
+        "ActivityInstanceId"
+    } else {
+        return ty;
+    };
+    format!("crate::types::ids::{id}")
+}
+
+fn integer_type(format: &str) -> &'static str {
+    match format {
+        "byte" => "u8",
+        "sbyte" => "i8",
+        "int16" => "i16",
+        "uint16" => "u16",
+        "uint32" => "u32",
+        "int64" => "i64",
+        "uint64" => "u64",
+        _ => "i32",
+    }
+}
+
+/// The last segment of a schema key, stripped of anything that cannot appear in an identifier.
+fn type_name(key: &str) -> String {
+    key.rsplit('.')
+        .next()
+        .unwrap()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == '_')
+        .collect()
+}
+
+fn snake_case(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut out = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next_lower) {
+                out.push('_');
+            }
+        }
+        out.extend(c.to_lowercase());
+    }
+
+    out
+}
+
+/// What serde's `rename_all = "camelCase"` produces from a snake case field name.
+fn camel_case(s: &str) -> String {
+    let mut out = String::new();
+    let mut upper = false;
+
+    for c in s.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            out.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+
+    out
+}
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "do", "dyn", "else", "enum",
+    "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let", "loop", "macro",
+    "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static", "struct",
+    "trait", "true", "try", "type", "typeof", "unsafe", "use", "virtual", "where", "while",
+    "yield",
+];
+
+/// The field name for a JSON property, and whether it needs an explicit `rename`.
+fn field_name(property: &str) -> (String, bool) {
+    let snake = snake_case(property);
+    let renamed = camel_case(&snake) != property;
+
+    if KEYWORDS.contains(&snake.as_str()) {
+        (format!("r#{snake}"), renamed)
+    } else if matches!(snake.as_str(), "self" | "super" | "crate") {
+        (format!("{snake}_"), true)
+    } else {
+        (snake, renamed)
+    }
+}
+
+fn doc(out: &mut String, schema: &Value, indent: &str) {
+    if let Some(description) = schema["description"].as_str() {
+        doc_lines(out, description, indent);
+    }
+}
+
+fn doc_lines(out: &mut String, text: &str, indent: &str) {
+    for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        let _ = writeln!(out, "{indent}/// {line}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use serde_json::Value;
+
+    use super::{Output, generate, wired};
+
+    fn outputs(root: &Path) -> Vec<Output> {
+        let text = fs::read_to_string(root.join("xtask").join("openapi.json")).unwrap();
+        let spec: Value = serde_json::from_str(&text).unwrap();
+        generate(root, &spec).unwrap()
+    }
+
+    #[test]
+    fn generated_code_is_up_to_date() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let outputs = outputs(root);
+        assert!(!outputs.is_empty());
+
+        for output in &outputs {
+            let committed = fs::read_to_string(&output.path).unwrap_or_default();
+            assert!(
+                committed == output.text,
+                "{} is out of date, run `cargo xtask codegen`",
+                output.path.display()
+            );
+            let module = fs::read_to_string(&output.module_file).unwrap();
+            assert!(
+                wired(&module, output.reexport).is_none(),
+                "{} does not declare `mod generated;`",
+                output.module_file.display()
+            );
+        }
+
+        let mut pending = vec![root.join("src")];
+        while let Some(dir) = pending.pop() {
+            for entry in fs::read_dir(&dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    pending.push(path);
+                } else if path.file_name().is_some_and(|n| n == "generated.rs") {
+                    assert!(
+                        outputs.iter().any(|o| o.path == path),
+                        "{} is not generated from xtask/openapi.json",
+                        path.display()
+                    );
+                }
+            }
+        }
+    }
+}